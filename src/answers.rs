@@ -0,0 +1,149 @@
+use std::fmt;
+
+/**
+ * The expected answers for one day's input.
+ *
+ * They're stored in the same shape the runner prints them in:
+ *
+ * ```text
+ * Part 1: 13140
+ * Part 2:
+ * ##..##..
+ * ###...##
+ * ```
+ *
+ * A `Part N:` line with nothing after it starts a multi-line answer, which runs until the next
+ * `Part N:` line or the end of the file.
+ *
+ * Days with parameters can list the values the answers were found with before the parts, like
+ * `Param row: 10`.
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub params: Vec<(String, i64)>,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part1 = Some(answer),
+            2 => self.part2 = Some(answer),
+            _ => panic!("Unknown part: {}", part),
+        }
+    }
+
+    /**
+     * Read an answers file. Returns the line number and contents of the first line that isn't a
+     * `Param name:` or `Part N:` header or part of a multi-line answer.
+     */
+    pub fn parse(text: &str) -> Result<Answers, (usize, String)> {
+        let mut answers = Answers::default();
+        let mut multiline: Option<(u8, Vec<&str>)> = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let header = line
+                .strip_prefix("Part ")
+                .and_then(|rest| rest.split_once(':'))
+                .and_then(|(part, answer)| Some((part.parse::<u8>().ok()?, answer.trim())))
+                .filter(|(part, _)| *part == 1 || *part == 2);
+
+            let param = line
+                .strip_prefix("Param ")
+                .and_then(|rest| rest.split_once(':'))
+                .and_then(|(name, value)| Some((name.trim(), value.trim().parse::<i64>().ok()?)));
+
+            match header {
+                Some((part, answer)) => {
+                    if let Some((part, lines)) = multiline.take() {
+                        answers.set(part, lines.join("\n"));
+                    }
+                    if answer.is_empty() {
+                        multiline = Some((part, vec![]));
+                    } else {
+                        answers.set(part, answer.to_string());
+                    }
+                }
+                None => match (multiline.as_mut(), param) {
+                    (Some((_, lines)), _) => lines.push(line),
+                    (None, Some((name, value))) => answers.params.push((name.to_string(), value)),
+                    (None, None) if line.trim().is_empty() => {}
+                    (None, None) => return Err((index + 1, line.to_string())),
+                },
+            }
+        }
+        if let Some((part, lines)) = multiline {
+            answers.set(part, lines.join("\n"));
+        }
+
+        Ok(answers)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, value) in &self.params {
+            writeln!(f, "Param {}: {}", name, value)?;
+        }
+        for part in [1, 2] {
+            match self.get(part) {
+                Some(answer) if answer.contains('\n') => writeln!(f, "Part {}:\n{}", part, answer)?,
+                Some(answer) => writeln!(f, "Part {}: {}", part, answer)?,
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let answers = Answers {
+            params: vec![],
+            part1: Some("13140".to_string()),
+            part2: Some("##..\n#..#".to_string()),
+        };
+        let text = answers.to_string();
+        assert_eq!(text, "Part 1: 13140\nPart 2:\n##..\n#..#\n");
+        assert_eq!(Answers::parse(&text), Ok(answers));
+    }
+
+    #[test]
+    fn test_missing_part() {
+        let answers = Answers::parse("Part 2: CMZ\n").unwrap();
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.get(2), Some("CMZ"));
+    }
+
+    #[test]
+    fn test_params() {
+        let answers = Answers::parse("Param row: 10\nParam max_x: 20\nPart 1: 26\n").unwrap();
+        assert_eq!(
+            answers.params,
+            [("row".to_string(), 10), ("max_x".to_string(), 20)]
+        );
+        assert_eq!(answers.get(1), Some("26"));
+        assert_eq!(Answers::parse(&answers.to_string()), Ok(answers));
+    }
+
+    #[test]
+    fn test_bad_line() {
+        assert_eq!(
+            Answers::parse("Part 1: 1\nPart three: 3"),
+            Err((2, "Part three: 3".to_string()))
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Sub};
+
+/**
+ * Whether solver arithmetic is overflow-checked. It only is when the crate is built with the
+ * `checked-arithmetic` feature; otherwise these functions are the plain operators, which panic on
+ * overflow in debug builds and silently wrap in release builds.
+ */
+pub const ENABLED: bool = cfg!(feature = "checked-arithmetic");
+
+/**
+ * The integer types solvers do arithmetic in.
+ */
+pub trait Integer:
+    Copy
+    + Default
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn checked_add(self, other: $t) -> Option<$t> {
+                    <$t>::checked_add(self, other)
+                }
+                fn checked_sub(self, other: $t) -> Option<$t> {
+                    <$t>::checked_sub(self, other)
+                }
+                fn checked_mul(self, other: $t) -> Option<$t> {
+                    <$t>::checked_mul(self, other)
+                }
+                fn checked_div(self, other: $t) -> Option<$t> {
+                    <$t>::checked_div(self, other)
+                }
+            }
+        )*
+    };
+}
+
+integer!(i32, i64, i128, u32, u64, usize);
+
+/**
+ * Report an overflow in `a operation b` on `day`, for arithmetic these functions can't do (like on
+ * big integers).
+ */
+#[cold]
+pub fn overflow(day: u8, a: impl Display, operation: char, b: impl Display) -> ! {
+    panic!(
+        "day {}: arithmetic overflow in {} {} {}",
+        day, a, operation, b
+    )
+}
+
+#[inline]
+fn apply<T: Integer>(
+    day: u8,
+    a: T,
+    operation: char,
+    b: T,
+    checked: fn(T, T) -> Option<T>,
+    plain: fn(T, T) -> T,
+) -> T {
+    if ENABLED {
+        checked(a, b).unwrap_or_else(|| overflow(day, a, operation, b))
+    } else {
+        plain(a, b)
+    }
+}
+
+/**
+ * `a + b`, reporting `day` and the operands if it overflows when checking is enabled.
+ */
+#[inline]
+pub fn add<T: Integer>(day: u8, a: T, b: T) -> T {
+    apply(day, a, '+', b, T::checked_add, T::add)
+}
+
+/**
+ * `a - b`, reporting `day` and the operands if it overflows when checking is enabled.
+ */
+#[inline]
+pub fn sub<T: Integer>(day: u8, a: T, b: T) -> T {
+    apply(day, a, '-', b, T::checked_sub, T::sub)
+}
+
+/**
+ * `a * b`, reporting `day` and the operands if it overflows when checking is enabled.
+ */
+#[inline]
+pub fn mul<T: Integer>(day: u8, a: T, b: T) -> T {
+    apply(day, a, '*', b, T::checked_mul, T::mul)
+}
+
+/**
+ * `a / b`, reporting `day` and the operands if it overflows (or divides by zero) when checking is
+ * enabled.
+ */
+#[inline]
+pub fn div<T: Integer>(day: u8, a: T, b: T) -> T {
+    apply(day, a, '/', b, T::checked_div, T::div)
+}
+
+/**
+ * The sum of `values`, added up with [`add`].
+ */
+pub fn sum<T: Integer>(day: u8, values: impl IntoIterator<Item = T>) -> T {
+    values
+        .into_iter()
+        .fold(T::default(), |total, value| add(day, total, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(add(1, 2, 3), 5);
+        assert_eq!(sub(1, 2i64, 3), -1);
+        assert_eq!(mul(1, 4u64, 5), 20);
+        assert_eq!(div(1, 7usize, 2), 3);
+        assert_eq!(sum(1, [1u32, 2, 3]), 6);
+    }
+
+    #[cfg(feature = "checked-arithmetic")]
+    #[test]
+    fn test_overflow() {
+        let error = std::panic::catch_unwind(|| mul(21, i64::MAX, 2)).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<String>().unwrap(),
+            "day 21: arithmetic overflow in 9223372036854775807 * 2"
+        );
+        assert!(std::panic::catch_unwind(|| sum(1, [u32::MAX, 1])).is_err());
+        assert!(std::panic::catch_unwind(|| div(20, 1, 0)).is_err());
+
+        let error = std::panic::catch_unwind(|| {
+            crate::day01::shared::parse_inventory("18446744073709551615\n1")
+        })
+        .unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<String>().unwrap(),
+            "day 1: arithmetic overflow in 18446744073709551615 + 1"
+        );
+
+        let error =
+            std::panic::catch_unwind(|| crate::day07::part2::run("$ cd /\n$ ls\n100 a.txt"))
+                .unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<String>().unwrap(),
+            "day 7: arithmetic overflow in 30000000 - 69999900"
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Mutex, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::days::Day;
+use super::format;
+use super::input::InputSource;
+
+/**
+ * What came of running one part, or of the steps before it.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(String),
+    NotImplemented,
+    Error(String),
+}
+
+/**
+ * One line of a day's results. Errors reading or parsing the input have no part.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub part: Option<u8>,
+    pub outcome: Outcome,
+    pub time: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayResult {
+    pub day: u8,
+    pub rows: Vec<Row>,
+    /// Hash of the input (see `format::input_hash`), or None if it couldn't be read
+    pub input_hash: Option<String>,
+    /// Wall time for the whole day, including reading and parsing the input
+    pub elapsed: Duration,
+}
+
+impl DayResult {
+    pub fn failed(&self) -> bool {
+        self.rows
+            .iter()
+            .any(|row| matches!(row.outcome, Outcome::Error(_)))
+    }
+}
+
+thread_local! {
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+    /// Whether this thread is running `quietly`
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+static QUIET_HOOK: Once = Once::new();
+
+/**
+ * Run `f`, turning a panic into an error message that includes where it happened. Run it
+ * `quietly` to keep the panic from also being printed.
+ */
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_else(|| panic_message(payload.as_ref()))
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        format!("panicked: {}", message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        format!("panicked: {}", message)
+    } else {
+        "panicked".to_string()
+    }
+}
+
+/**
+ * Run the selected parts of one day, timing each one. Read and parse errors, and panics anywhere,
+ * become error rows.
+ */
+pub fn run_day(
+    day: &Day,
+    parts: &[u8],
+    source: &InputSource,
+    params: &[(String, i64)],
+) -> DayResult {
+    let start = Instant::now();
+    let mut input_hash = None;
+    let rows = catch(|| {
+        let input = match source.load(day) {
+            Ok(input) => input,
+            Err(e) => return Err(format!("could not read input: {}", e)),
+        };
+        input_hash = Some(format::input_hash(&input));
+        let solution = day.solution(&day.params(params));
+        let parsed = solution
+            .parse(&input)
+            .map_err(|e| format!("could not parse input: {}", e))?;
+        Ok(parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let outcome = match catch(|| solution.part(part, parsed.as_ref())) {
+                    Ok(Some(answer)) => Outcome::Answer(answer),
+                    Ok(None) => Outcome::NotImplemented,
+                    Err(e) => Outcome::Error(e),
+                };
+                Row {
+                    part: Some(part),
+                    outcome,
+                    time: start.elapsed(),
+                }
+            })
+            .collect::<Vec<_>>())
+    });
+    let rows = match rows {
+        Ok(Ok(rows)) => rows,
+        Ok(Err(e)) | Err(e) => vec![Row {
+            part: None,
+            outcome: Outcome::Error(e),
+            time: start.elapsed(),
+        }],
+    };
+    DayResult {
+        day: day.day,
+        rows,
+        input_hash,
+        elapsed: start.elapsed(),
+    }
+}
+
+/**
+ * Run every day in `days` on a pool of `jobs` threads. `finished` is called with each day's
+ * result as soon as it's done, so slow days don't hold up reporting on the others.
+ *
+ * Panics are caught and reported as error rows. Each worker runs `quietly`, so they aren't also
+ * printed.
+ *
+ * Returns the results in the same order as `days`.
+ */
+pub fn run_parallel(
+    days: &[Day],
+    jobs: usize,
+    parts: &[u8],
+    source: &InputSource,
+    params: &[(String, i64)],
+    mut finished: impl FnMut(&DayResult),
+) -> Vec<DayResult> {
+    let queue = Mutex::new(days.iter());
+    let (sender, receiver) = mpsc::channel();
+    let mut results = thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            let sender = sender.clone();
+            let queue = &queue;
+            scope.spawn(move || {
+                quietly(|| loop {
+                    let Some(day) = queue.lock().unwrap().next() else {
+                        break;
+                    };
+                    let result = run_day(day, parts, source, params);
+                    if sender.send(result).is_err() {
+                        break;
+                    }
+                })
+            });
+        }
+        drop(sender);
+
+        receiver
+            .iter()
+            .inspect(|result| finished(result))
+            .collect::<Vec<_>>()
+    });
+
+    results.sort_by_key(|result| days.iter().position(|day| day.day == result.day));
+    results
+}
+
+/**
+ * Run `f` so panics on this thread that are caught by `run_day` are only reported in its error rows
+ * instead of also being printed. Other threads' panics are printed as usual.
+ *
+ * The first call wraps the panic hook in one that checks which thread panicked. It's installed
+ * once and left in place, since swapping the hook back and forth would race with other threads.
+ */
+pub fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    QUIET_HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if QUIET.with(Cell::get) {
+                let message = info.to_string().replace('\n', " ");
+                LAST_PANIC.with(|last| *last.borrow_mut() = Some(message));
+            } else {
+                hook(info);
+            }
+        }));
+    });
+
+    /**
+     * Puts back the thread's previous setting, even if `f` panics.
+     */
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            QUIET.with(|quiet| quiet.set(self.0));
+        }
+    }
+
+    let _restore = Restore(QUIET.with(|quiet| quiet.replace(true)));
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_errors_become_rows() {
+        let days = days::all()
+            .into_iter()
+            .filter(|day| day.day <= 2)
+            .collect::<Vec<_>>();
+        // one directory per process, so concurrent test runs don't share files
+        let dir = std::env::temp_dir().join(format!("aoc-batch-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("day01.txt"), "1000\n2000\n\n3000\n\n4000\n\n500").unwrap();
+        std::fs::write(dir.join("day02.txt"), "A Y\nB Q").unwrap();
+
+        let mut seen = vec![];
+        let results = run_parallel(
+            &days,
+            2,
+            &[1, 2],
+            &InputSource::Directory(dir.clone()),
+            &[],
+            |result| seen.push(result.day),
+        );
+
+        seen.sort();
+        assert_eq!(seen, [1, 2]);
+        assert_eq!(results[0].day, 1);
+        assert!(!results[0].failed());
+        assert_eq!(
+            results[0].rows[0].outcome,
+            Outcome::Answer("4000".to_string())
+        );
+        assert!(results[1].failed());
+        assert_eq!(results[1].rows.len(), 1);
+        assert_eq!(results[1].rows[0].part, None);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_quietly() {
+        assert!(!QUIET.with(Cell::get));
+        let error = quietly(|| catch(|| panic!("hushed")).unwrap_err());
+        assert!(error.contains("hushed"), "{}", error);
+        assert!(!QUIET.with(Cell::get));
+
+        // only the thread running quietly is quiet
+        let other = quietly(|| thread::spawn(|| QUIET.with(Cell::get)).join().unwrap());
+        assert!(!other);
+
+        let _ = catch(|| quietly(|| panic!("still restored")));
+        assert!(!QUIET.with(Cell::get));
+    }
+
+    #[test]
+    fn test_catch() {
+        let error = catch(|| panic!("oh no")).unwrap_err();
+        assert!(error.contains("oh no"), "{}", error);
+    }
+}
@@ -0,0 +1,317 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use super::parse::ParseError;
+use super::solution::DynSolution;
+
+/**
+ * One timed step of a day's solution.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Stage> {
+        match name {
+            "parse" => Some(Stage::Parse),
+            "part1" => Some(Stage::Part1),
+            "part2" => Some(Stage::Part2),
+            _ => None,
+        }
+    }
+
+    pub fn part(part: u8) -> Stage {
+        match part {
+            1 => Stage::Part1,
+            2 => Stage::Part2,
+            _ => panic!("Unknown part: {}", part),
+        }
+    }
+}
+
+/**
+ * The spread of a stage's run times over every iteration.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    /**
+     * Summarize a non-empty set of samples. For an even number of samples the median is the mean
+     * of the middle two.
+     */
+    pub fn from_samples(samples: &[Duration]) -> Timings {
+        let mut samples = samples.to_vec();
+        samples.sort();
+        let (low, high) = middle(&samples);
+        Timings {
+            min: samples[0],
+            median: (*low + *high) / 2,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/**
+ * The two middle values of a sorted, non-empty slice, whose mean is its median. For an odd length
+ * they're both the middle value.
+ */
+pub fn middle<T>(sorted: &[T]) -> (&T, &T) {
+    let middle = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (&sorted[middle - 1], &sorted[middle])
+    } else {
+        (&sorted[middle], &sorted[middle])
+    }
+}
+
+/**
+ * Call `f` `iterations` times (at least once), timing each call. Returns the timings along with
+ * the result of the last call.
+ */
+pub fn time<T>(iterations: usize, mut f: impl FnMut() -> T) -> (Timings, T) {
+    let mut samples = vec![];
+    let mut result = None;
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let value = black_box(f());
+        samples.push(start.elapsed());
+        result = Some(value);
+    }
+    (Timings::from_samples(&samples), result.unwrap())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub timings: Timings,
+}
+
+/**
+ * Time parsing the input, then each of the requested parts on the parsed result. Parts that
+ * aren't implemented are skipped.
+ */
+pub fn bench_day(
+    day: u8,
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[u8],
+    iterations: usize,
+) -> Result<Vec<Measurement>, ParseError> {
+    let mut measurements = vec![];
+
+    let (timings, parsed) = time(iterations, || solution.parse(input));
+    let parsed = parsed?;
+    measurements.push(Measurement {
+        day,
+        stage: Stage::Parse,
+        timings,
+    });
+
+    for &part in parts.iter().filter(|&&part| solution.has_part(part)) {
+        let (timings, _) = time(iterations, || solution.part(part, parsed.as_ref()));
+        measurements.push(Measurement {
+            day,
+            stage: Stage::part(part),
+            timings,
+        });
+    }
+
+    Ok(measurements)
+}
+
+/**
+ * Saved measurements to compare later runs against.
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    pub measurements: Vec<Measurement>,
+}
+
+impl Baseline {
+    pub fn get(&self, day: u8, stage: Stage) -> Option<&Measurement> {
+        self.measurements
+            .iter()
+            .find(|m| m.day == day && m.stage == stage)
+    }
+
+    /**
+     * Replace any saved measurements for the same day and stage with `measurements`, keeping the
+     * rest, so benchmarking one day doesn't drop the others from the baseline.
+     */
+    pub fn update(&mut self, measurements: &[Measurement]) {
+        for measurement in measurements {
+            self.measurements
+                .retain(|m| m.day != measurement.day || m.stage != measurement.stage);
+            self.measurements.push(*measurement);
+        }
+        self.measurements.sort_by_key(|m| (m.day, m.stage.name()));
+    }
+
+    pub fn to_json(&self) -> Value {
+        let measurements = self
+            .measurements
+            .iter()
+            .map(|m| {
+                json!({
+                    "day": m.day,
+                    "stage": m.stage.name(),
+                    "min_ns": m.timings.min.as_nanos() as u64,
+                    "median_ns": m.timings.median.as_nanos() as u64,
+                    "max_ns": m.timings.max.as_nanos() as u64,
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({ "measurements": measurements })
+    }
+
+    pub fn from_json(json: &Value) -> Result<Baseline, String> {
+        let entries = json["measurements"]
+            .as_array()
+            .ok_or("expected a `measurements` list")?;
+        let measurements = entries
+            .iter()
+            .map(|entry| {
+                let nanos = |key: &str| {
+                    entry[key]
+                        .as_u64()
+                        .map(Duration::from_nanos)
+                        .ok_or(format!("expected `{}` to be a number of nanoseconds", key))
+                };
+                Ok(Measurement {
+                    day: entry["day"]
+                        .as_u64()
+                        .and_then(|day| u8::try_from(day).ok())
+                        .ok_or("expected `day` to be a day number")?,
+                    stage: entry["stage"]
+                        .as_str()
+                        .and_then(Stage::from_name)
+                        .ok_or("expected `stage` to be parse, part1 or part2")?,
+                    timings: Timings {
+                        min: nanos("min_ns")?,
+                        median: nanos("median_ns")?,
+                        max: nanos("max_ns")?,
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(Baseline { measurements })
+    }
+}
+
+/**
+ * How much slower (positive) or faster (negative) the current median is than the baseline's, as a
+ * fraction of the baseline.
+ */
+pub fn change(current: &Timings, baseline: &Timings) -> f64 {
+    let baseline = baseline.median.as_secs_f64();
+    if baseline == 0.0 {
+        return 0.0;
+    }
+    current.median.as_secs_f64() / baseline - 1.0
+}
+
+/**
+ * Displays a duration compactly in the largest fitting unit, like `812ns`, `45.2µs` or `1.53s`.
+ */
+pub struct Short(pub Duration);
+
+impl fmt::Display for Short {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos();
+        let text = if nanos < 1_000 {
+            format!("{}ns", nanos)
+        } else if nanos < 1_000_000 {
+            format!("{:.1}µs", nanos as f64 / 1e3)
+        } else if nanos < 1_000_000_000 {
+            format!("{:.1}ms", nanos as f64 / 1e6)
+        } else {
+            format!("{:.2}s", nanos as f64 / 1e9)
+        };
+        f.pad(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn test_timings() {
+        let timings = Timings::from_samples(&[ms(5), ms(1), ms(3)]);
+        assert_eq!(
+            (timings.min, timings.median, timings.max),
+            (ms(1), ms(3), ms(5))
+        );
+
+        let timings = Timings::from_samples(&[ms(4), ms(1), ms(2), ms(9)]);
+        assert_eq!(timings.median, ms(3));
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        let timings = Timings::from_samples(&[ms(1), ms(2), ms(4)]);
+        baseline.update(&[
+            Measurement {
+                day: 20,
+                stage: Stage::Part1,
+                timings,
+            },
+            Measurement {
+                day: 15,
+                stage: Stage::Parse,
+                timings,
+            },
+        ]);
+        let faster = Timings::from_samples(&[ms(1)]);
+        baseline.update(&[Measurement {
+            day: 20,
+            stage: Stage::Part1,
+            timings: faster,
+        }]);
+
+        assert_eq!(baseline.measurements.len(), 2);
+        assert_eq!(baseline.get(20, Stage::Part1).unwrap().timings, faster);
+        assert_eq!(Baseline::from_json(&baseline.to_json()), Ok(baseline));
+    }
+
+    #[test]
+    fn test_change() {
+        let before = Timings::from_samples(&[ms(10)]);
+        let after = Timings::from_samples(&[ms(15)]);
+        assert!((change(&after, &before) - 0.5).abs() < 1e-9);
+        assert!((change(&before, &after) + 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_short() {
+        assert_eq!(Short(Duration::from_nanos(812)).to_string(), "812ns");
+        assert_eq!(Short(Duration::from_nanos(45_210)).to_string(), "45.2µs");
+        assert_eq!(Short(Duration::from_millis(1530)).to_string(), "1.53s");
+    }
+}
@@ -0,0 +1,184 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::answers::Answers;
+use super::batch::{self, Outcome, Row};
+use super::days::Day;
+use super::input::InputSource;
+
+/**
+ * One named input in a day's corpus, like `alice.txt`, with its known answers in
+ * `alice.answers.txt` (in the same format as `inputs/answers.txt`).
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub name: String,
+    pub input: PathBuf,
+    pub answers: PathBuf,
+}
+
+/**
+ * Every input in a corpus directory, sorted by name. A directory that doesn't exist is an empty
+ * corpus.
+ */
+pub fn entries(dir: &Path) -> io::Result<Vec<Entry>> {
+    let listing = match fs::read_dir(dir) {
+        Ok(listing) => listing,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    let mut entries = vec![];
+    for entry in listing {
+        let input = entry?.path();
+        let file_name = input.file_name().unwrap().to_string_lossy().into_owned();
+        let Some(name) = file_name.strip_suffix(".txt") else {
+            continue;
+        };
+        if name.ends_with(".answers") {
+            continue;
+        }
+        entries.push(Entry {
+            name: name.to_string(),
+            answers: input.with_extension("answers.txt"),
+            input,
+        });
+    }
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(entries)
+}
+
+/**
+ * How one part's answer compared to the known one.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// The answers file has no answer for this part
+    Missing,
+    /// The input or answers couldn't be read or parsed, or the part panicked
+    Error,
+}
+
+/**
+ * One row of a corpus entry's results: the part's answer and time, and whether it was right.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub row: Row,
+    pub status: Status,
+}
+
+/**
+ * Run the selected parts of `day` on a corpus entry, using the parameters listed in its answers
+ * file, and compare each answer against the known one.
+ */
+pub fn check(day: &Day, entry: &Entry, parts: &[u8]) -> Vec<Check> {
+    let error = |message: String| {
+        vec![Check {
+            row: Row {
+                part: None,
+                outcome: Outcome::Error(message),
+                time: Default::default(),
+            },
+            status: Status::Error,
+        }]
+    };
+    let answers = match fs::read_to_string(&entry.answers) {
+        Ok(text) => match Answers::parse(&text) {
+            Ok(answers) => answers,
+            Err((line, text)) => {
+                return error(format!(
+                    "{} line {}: expected `Param name: value` or `Part N: answer`, found {:?}",
+                    entry.answers.display(),
+                    line,
+                    text
+                ))
+            }
+        },
+        Err(e) => return error(format!("could not read {}: {}", entry.answers.display(), e)),
+    };
+    if let Some((name, _)) = answers.params.iter().find(|(name, _)| !day.has_param(name)) {
+        return error(format!("unknown parameter {}", name));
+    }
+
+    let solution = day.solution(&day.params(&answers.params));
+    let parts = parts
+        .iter()
+        .copied()
+        .filter(|&part| solution.has_part(part))
+        .collect::<Vec<_>>();
+    let source = InputSource::File(entry.input.clone());
+    batch::run_day(day, &parts, &source, &answers.params)
+        .rows
+        .into_iter()
+        .map(|row| {
+            let status = match (&row.outcome, row.part.and_then(|part| answers.get(part))) {
+                (Outcome::Answer(answer), Some(expected)) if answer == expected => Status::Pass,
+                (Outcome::Answer(_), Some(expected)) => Status::Fail {
+                    expected: expected.to_string(),
+                },
+                (Outcome::Answer(_), None) => Status::Missing,
+                (Outcome::NotImplemented | Outcome::Error(_), _) => Status::Error,
+            };
+            Check { row, status }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_check_corpus() {
+        let day = days::get(1).unwrap();
+        let dir = std::env::temp_dir()
+            .join("aoc-corpus-test")
+            .join("corpus/day01");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("alice.txt"), "1000\n2000\n\n3000\n\n4000\n\n500\n").unwrap();
+        std::fs::write(dir.join("alice.answers.txt"), "Part 1: 4000\nPart 2: 7\n").unwrap();
+        std::fs::write(dir.join("bob.txt"), "10\n\n20\n\n5").unwrap();
+        std::fs::write(dir.join("bob.answers.txt"), "Part 1: 20\n").unwrap();
+        std::fs::write(dir.join("carol.txt"), "lots").unwrap();
+        std::fs::write(dir.join("carol.answers.txt"), "Part 1: 1\n").unwrap();
+
+        let source = InputSource::Directory(std::env::temp_dir().join("aoc-corpus-test"));
+        assert_eq!(source.corpus_dir(&day), Some(dir.clone()));
+        let entries = entries(&dir).unwrap();
+        let names = entries
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["alice", "bob", "carol"]);
+
+        let statuses = |entry| {
+            check(&day, entry, &[1, 2])
+                .into_iter()
+                .map(|check| check.status)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            statuses(&entries[0]),
+            [
+                Status::Pass,
+                Status::Fail {
+                    expected: "7".to_string()
+                }
+            ]
+        );
+        assert_eq!(statuses(&entries[1]), [Status::Pass, Status::Missing]);
+        assert_eq!(statuses(&entries[2]), [Status::Error]);
+    }
+
+    #[test]
+    fn test_missing_corpus() {
+        let dir = std::env::temp_dir().join("aoc-corpus-test-missing");
+        assert_eq!(entries(&dir).unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,16 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * Calorie lists for `size` elves (at least 3), each carrying 1 to 15 snacks.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(3))
+        .map(|_| {
+            (0..rng.range(1..=15))
+                .map(|_| rng.range(1000..=60000).to_string())
+                .join("\n")
+        })
+        .join("\n\n")
+}
@@ -1,12 +1,14 @@
-mod part1;
-mod part2;
+use aoc::day01::{part1, part2};
+use aoc::parse::ParseError;
 
-fn main() {
+fn main() -> Result<(), ParseError> {
     let input = include_str!("inputs/input.txt");
-    
+
     println!("Part 1");
-    println!("Largest inventory: {}", part1::run(input));
-    
+    println!("Largest inventory: {}", part1::run(input)?);
+
     println!("Part 2");
-    println!("Top 3 inventories: {}", part2::run(input));
-}
\ No newline at end of file
+    println!("Top 3 inventories: {}", part2::run(input)?);
+
+    Ok(())
+}
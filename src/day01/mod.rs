@@ -0,0 +1,27 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+use shared::Inventory;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Inventory;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Inventory, ParseError> {
+        shared::parse_inventory(input)
+    }
+    fn part1(&self, inventory: &Inventory) -> u64 {
+        part1::solve(inventory)
+    }
+    fn part2(&self, inventory: &Inventory) -> u64 {
+        part2::solve(inventory)
+    }
+}
@@ -1,30 +1,23 @@
+use std::io::BufRead;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+use super::shared::*;
+use crate::parse::ParseError;
+use crate::stream::StreamError;
 
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 24000u32);
-    }
+pub fn solve(inventory: &Inventory) -> u64 {
+    // Get the largest inventory, or 0 if there are no elves
+    inventory.top(1).first().map_or(0, |elf| elf.total())
 }
 
-pub fn run(input: &str) -> u32 {
-    let mut elves: Vec<u32> = Vec::new();
-    elves.push(0); // initialize first elf's inventory
-
-    // Sum the elves' inventories
-    for line in input.split('\n') {
-        if line.is_empty() {
-            elves.push(0);
-            continue;
-        }
-        let last_index = elves.len() - 1;
-        let amount: u32 = line.parse().unwrap_or_else(|_| panic!("Could not parse number from line: {}", line));
-        elves[last_index] += amount;
-    }
+pub fn run(input: &str) -> Result<u64, ParseError> {
+    Ok(solve(&parse_inventory(input)?))
+}
 
-    // Get the largest inventory
-    *elves.iter().max().unwrap()
-}
\ No newline at end of file
+/**
+ * Like [`run`], but reads the input as it goes, keeping only the largest inventory so far.
+ */
+pub fn run_reader(reader: impl BufRead) -> Result<u64, StreamError> {
+    let mut largest = 0;
+    for_each_inventory(reader, |total| largest = largest.max(total))?;
+    Ok(largest)
+}
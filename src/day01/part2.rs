@@ -1,31 +1,30 @@
-#[cfg(test)]
-mod tests {
-    use super::*;
+use std::io::BufRead;
 
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 45000u32);
-    }
+use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
+use crate::stream::StreamError;
+
+pub fn solve(inventory: &Inventory) -> u64 {
+    // Get the top three inventories
+    arith::sum(1, inventory.top(3).iter().map(|elf| elf.total()))
 }
 
-pub fn run(input: &str) -> u32 {
-    let mut elves: Vec<u32> = Vec::new();
-    elves.push(0); // initialize first elf's inventory
+pub fn run(input: &str) -> Result<u64, ParseError> {
+    Ok(solve(&parse_inventory(input)?))
+}
 
-    // Sum the elves' inventories
-    for line in input.split('\n') {
-        if line.is_empty() {
-            elves.push(0);
-            continue;
+/**
+ * Like [`run`], but reads the input as it goes, keeping only the top three inventories so far.
+ */
+pub fn run_reader(reader: impl BufRead) -> Result<u64, StreamError> {
+    // largest first
+    let mut top = [0; 3];
+    for_each_inventory(reader, |total| {
+        if let Some(index) = top.iter().position(|&t| total > t) {
+            top[index..].rotate_right(1);
+            top[index] = total;
         }
-        let last_index = elves.len() - 1;
-        let amount: u32 = line.parse().unwrap_or_else(|_| panic!("Could not parse number from line: {}", line));
-        elves[last_index] += amount;
-    }
-
-    // Get the largest inventory
-    elves.sort();
-    elves.reverse();
-    elves[0 .. 3].iter().sum::<u32>()
-}
\ No newline at end of file
+    })?;
+    Ok(arith::sum(1, top))
+}
@@ -0,0 +1,257 @@
+use std::io::BufRead;
+
+use crate::arith;
+use crate::bench;
+use crate::parse::{self, ParseError};
+use crate::stream::{for_each_line, StreamError};
+
+/**
+ * One elf and the calories of each item it carries. `index` is its position in the input, from 0.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    pub fn total(&self) -> u64 {
+        arith::sum(1, self.items.iter().copied())
+    }
+}
+
+/**
+ * Summary statistics of the elves' totals.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub elves: usize,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+}
+
+/**
+ * How many elves have a total in `start..end`.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: u64,
+    pub end: u64,
+    pub elves: usize,
+}
+
+/**
+ * Every elf in the input, with each one's total worked out once up front.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    elves: Vec<Elf>,
+    totals: Vec<u64>,
+}
+
+impl Inventory {
+    pub fn new(elves: Vec<Elf>) -> Inventory {
+        let totals = elves.iter().map(Elf::total).collect();
+        Inventory { elves, totals }
+    }
+
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    /**
+     * Each elf's total, in input order.
+     */
+    pub fn totals(&self) -> &[u64] {
+        &self.totals
+    }
+
+    /**
+     * The `n` elves carrying the most calories, most first, with ties in input order. Only the top
+     * `n` are sorted, after partitioning them from the rest.
+     */
+    pub fn top(&self, n: usize) -> Vec<&Elf> {
+        let by_total = |a: &usize, b: &usize| self.totals[*b].cmp(&self.totals[*a]).then(a.cmp(b));
+        if n == 0 {
+            return vec![];
+        }
+        let mut order = (0..self.elves.len()).collect::<Vec<_>>();
+        if n < order.len() {
+            order.select_nth_unstable_by(n - 1, by_total);
+            order.truncate(n);
+        }
+        order.sort_unstable_by(by_total);
+        order.into_iter().map(|index| &self.elves[index]).collect()
+    }
+
+    /**
+     * The rank of the elf at `index` by total, where 1 is the most calories. Elves with the same
+     * total share a rank. None if there's no such elf.
+     */
+    pub fn rank(&self, index: usize) -> Option<usize> {
+        let total = *self.totals.get(index)?;
+        Some(1 + self.totals.iter().filter(|&&other| other > total).count())
+    }
+
+    /**
+     * None if there are no elves.
+     */
+    pub fn summary(&self) -> Option<Summary> {
+        let mut totals = self.totals.clone();
+        totals.sort_unstable();
+        let (&min, &max) = (totals.first()?, totals.last()?);
+        let (&low, &high) = bench::middle(&totals);
+        Some(Summary {
+            elves: totals.len(),
+            min,
+            max,
+            mean: totals.iter().map(|&total| total as f64).sum::<f64>() / totals.len() as f64,
+            median: (low as f64 + high as f64) / 2.0,
+        })
+    }
+
+    /**
+     * The totals counted into `buckets` equal ranges from the smallest total to the largest. Empty if
+     * there are no elves or no buckets.
+     */
+    pub fn distribution(&self, buckets: usize) -> Vec<Bucket> {
+        let Some(summary) = self.summary() else {
+            return vec![];
+        };
+        if buckets == 0 {
+            return vec![];
+        }
+        let width = (summary.max - summary.min) / buckets as u64 + 1;
+        let mut distribution = (0..buckets as u64)
+            .map(|bucket| Bucket {
+                start: summary.min + bucket * width,
+                end: summary.min + (bucket + 1) * width,
+                elves: 0,
+            })
+            .collect::<Vec<_>>();
+        for total in &self.totals {
+            distribution[((total - summary.min) / width) as usize].elves += 1;
+        }
+        distribution
+    }
+}
+
+/**
+ * Read each elf's items. Each line is one item's calories, and elves are separated by blank lines.
+ * Extra blank lines, including a trailing newline, don't add empty elves.
+ */
+pub fn parse_inventory(input: &str) -> Result<Inventory, ParseError> {
+    let elves = parse::blocks(1, input)
+        .iter()
+        .enumerate()
+        .map(|(index, block)| {
+            let items = block
+                .lines()
+                .iter()
+                .map(|&(number, line)| {
+                    line.parse()
+                        .map_err(|_| ParseError::line(1, number, line, "a calorie count"))
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(Elf { index, items })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Inventory::new(elves))
+}
+
+/**
+ * Stream each elf's inventory total from `reader` to `f`, one line at a time, like
+ * [`parse_inventory`] does for an input in memory.
+ */
+pub fn for_each_inventory(reader: impl BufRead, mut f: impl FnMut(u64)) -> Result<(), StreamError> {
+    // None until the current elf has an item, so runs of blank lines don't make empty elves
+    let mut total = None;
+    for_each_line(reader, |number, line| {
+        if line.trim().is_empty() {
+            if let Some(total) = total.take() {
+                f(total);
+            }
+        } else {
+            let amount = line
+                .parse::<u64>()
+                .map_err(|_| ParseError::line(1, number, line, "a calorie count"))?;
+            total = Some(arith::add(1, total.unwrap_or(0), amount));
+        }
+        Ok(())
+    })?;
+    if let Some(total) = total {
+        f(total);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inventory() {
+        let inventory = parse_inventory(include_str!("inputs/sample.txt")).unwrap();
+        assert_eq!(inventory.totals(), [6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(inventory.elves()[2].items, vec![5000, 6000]);
+
+        let top = inventory.top(3);
+        assert_eq!(
+            top.iter().map(|elf| elf.index).collect::<Vec<_>>(),
+            [3, 2, 4]
+        );
+        assert_eq!(inventory.top(0), Vec::<&Elf>::new());
+        assert_eq!(inventory.top(10).len(), 5);
+
+        assert_eq!(inventory.rank(3), Some(1));
+        assert_eq!(inventory.rank(1), Some(5));
+        assert_eq!(inventory.rank(5), None);
+
+        let summary = inventory.summary().unwrap();
+        assert_eq!((summary.elves, summary.min, summary.max), (5, 4000, 24000));
+        assert_eq!((summary.mean, summary.median), (11000.0, 10000.0));
+
+        let distribution = inventory.distribution(2);
+        assert_eq!(
+            distribution,
+            vec![
+                Bucket {
+                    start: 4000,
+                    end: 14001,
+                    elves: 4
+                },
+                Bucket {
+                    start: 14001,
+                    end: 24002,
+                    elves: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ties() {
+        let inventory = parse_inventory("5\n\n7\n\n5").unwrap();
+        let top = inventory.top(2);
+        assert_eq!(top.iter().map(|elf| elf.index).collect::<Vec<_>>(), [1, 0]);
+        assert_eq!(inventory.rank(0), Some(2));
+        assert_eq!(inventory.rank(2), Some(2));
+        assert_eq!(inventory.summary().unwrap().median, 5.0);
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        let inventory = parse_inventory("5\n6\n\n\n7\n").unwrap();
+        assert_eq!(inventory.totals(), [11, 7]);
+        assert_eq!(inventory.elves()[1].index, 1);
+        let summary = inventory.summary().unwrap();
+        assert_eq!((summary.min, summary.median), (7, 9.0));
+        assert_eq!(parse_inventory("").unwrap().summary(), None);
+
+        let error = parse_inventory("5\n\nsix").unwrap_err().to_string();
+        assert!(error.starts_with("day 1, line 3, column 1:"), "{}", error);
+    }
+}
@@ -0,0 +1,18 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * A strategy guide with `size` rounds.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            format!(
+                "{} {}",
+                rng.pick(&['A', 'B', 'C']),
+                rng.pick(&['X', 'Y', 'Z'])
+            )
+        })
+        .join("\n")
+}
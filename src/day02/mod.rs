@@ -0,0 +1,25 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<shared::Round>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Vec<shared::Round>, ParseError> {
+        shared::parse_rounds(input)
+    }
+    fn part1(&self, rounds: &Vec<shared::Round>) -> i32 {
+        part1::solve(rounds)
+    }
+    fn part2(&self, rounds: &Vec<shared::Round>) -> i32 {
+        part2::solve(rounds)
+    }
+}
@@ -1,31 +1,37 @@
-pub fn run(input: &str) -> i32 {
-    let mut score: i32 = 0;
+use std::io::BufRead;
 
-    for line in input.split('\n') {
-        score += match line {
-            "A X" => 3 + 0, // Lose  Rock - Scissors
-            "B X" => 1 + 0, //       Paper - Rock
-            "C X" => 2 + 0, //       Scissors - Paper
-            "A Y" => 1 + 3, // Tie   Rock - Rock
-            "B Y" => 2 + 3, //       Paper - Paper
-            "C Y" => 3 + 3, //       Scissors - Scissors
-            "A Z" => 2 + 6, // Win   Rock - Paper
-            "B Z" => 3 + 6, //       Paper - Scissors
-            "C Z" => 1 + 6, //       Scissors - Rock
-            &_ => panic!("Unknown option encountered")
-        }
+use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
+use crate::stream::StreamError;
+
+#[allow(clippy::identity_op)]
+pub fn score(round: &Round) -> i32 {
+    match round {
+        ('A', 'X') => 3 + 0, // Lose  Rock - Scissors
+        ('B', 'X') => 1 + 0, //       Paper - Rock
+        ('C', 'X') => 2 + 0, //       Scissors - Paper
+        ('A', 'Y') => 1 + 3, // Tie   Rock - Rock
+        ('B', 'Y') => 2 + 3, //       Paper - Paper
+        ('C', 'Y') => 3 + 3, //       Scissors - Scissors
+        ('A', 'Z') => 2 + 6, // Win   Rock - Paper
+        ('B', 'Z') => 3 + 6, //       Paper - Scissors
+        ('C', 'Z') => 1 + 6, //       Scissors - Rock
+        _ => unreachable!("parse_rounds only accepts A-C and X-Z"),
     }
+}
 
-    score
+pub fn solve(rounds: &[Round]) -> i32 {
+    arith::sum(2, rounds.iter().map(score))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub fn run(input: &str) -> Result<i32, ParseError> {
+    Ok(solve(&parse_rounds(input)?))
+}
 
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 12i32);
-    }
-}
\ No newline at end of file
+/**
+ * Like [`run`], but scores the rounds as they're read.
+ */
+pub fn run_reader(reader: impl BufRead) -> Result<i32, StreamError> {
+    tally(reader, score)
+}
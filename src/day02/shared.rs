@@ -0,0 +1,49 @@
+use std::io::BufRead;
+
+use crate::arith;
+use crate::parse::ParseError;
+use crate::stream::{for_each_line, StreamError};
+
+/**
+ * A round of the strategy guide: the opponent's column (A, B or C) and our column (X, Y or Z).
+ */
+pub type Round = (char, char);
+
+pub fn parse_rounds(input: &str) -> Result<Vec<Round>, ParseError> {
+    input
+        .split('\n')
+        .enumerate()
+        .map(|(index, line)| parse_round(index + 1, line))
+        .collect()
+}
+
+/**
+ * Parse line `number` of the strategy guide.
+ */
+pub fn parse_round(number: usize, line: &str) -> Result<Round, ParseError> {
+    let (opponent, response) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::line(2, number, line, "two columns like `A Y`"))?;
+    if !matches!(opponent, "A" | "B" | "C") {
+        return Err(ParseError::within(2, number, line, opponent, "A, B or C"));
+    }
+    if !matches!(response, "X" | "Y" | "Z") {
+        return Err(ParseError::within(2, number, line, response, "X, Y or Z"));
+    }
+    Ok((
+        opponent.chars().next().unwrap(),
+        response.chars().next().unwrap(),
+    ))
+}
+
+/**
+ * Add up the score of every round in `reader` one line at a time, without keeping the rounds.
+ */
+pub fn tally(reader: impl BufRead, score: impl Fn(&Round) -> i32) -> Result<i32, StreamError> {
+    let mut total = 0;
+    for_each_line(reader, |number, line| {
+        total = arith::add(2, total, score(&parse_round(number, line)?));
+        Ok(())
+    })?;
+    Ok(total)
+}
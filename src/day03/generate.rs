@@ -0,0 +1,51 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/**
+ * `size` groups of three rucksacks (so `size * 3` lines). Each rucksack's compartments share
+ * exactly one item type, and each group shares exactly one badge.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1)).flat_map(|_| group(rng)).join("\n")
+}
+
+fn group(rng: &mut Rng) -> Vec<String> {
+    let mut items = ITEMS.chars().collect::<Vec<_>>();
+    rng.shuffle(&mut items);
+    // the other 51 items are split between the three elves so only the badge is common
+    let badge = items[0];
+    items[1..]
+        .chunks(17)
+        .map(|pool| rucksack(rng, badge, pool))
+        .collect()
+}
+
+fn rucksack(rng: &mut Rng, badge: char, pool: &[char]) -> String {
+    let mut items = pool.to_vec();
+    items.push(badge);
+    rng.shuffle(&mut items);
+    // the item in both compartments, then the rest split between them
+    let shared = items[0];
+    let (left, right) = items[1..].split_at(items.len() / 2);
+    let half = rng.below(12) + 2;
+    let mut compartments = [left, right].map(|pool| {
+        let mut compartment = vec![shared];
+        compartment.extend((1..half).map(|_| *rng.pick(pool)));
+        compartment
+    });
+    // the badge has to be somewhere in the rucksack
+    if shared != badge {
+        let side = usize::from(right.contains(&badge));
+        compartments[side][1] = badge;
+    }
+    compartments
+        .iter_mut()
+        .map(|compartment| {
+            rng.shuffle(compartment);
+            compartment.iter().collect::<String>()
+        })
+        .collect()
+}
@@ -0,0 +1,25 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Vec<Vec<usize>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+        shared::parse_rucksacks(input)
+    }
+    fn part1(&self, rucksacks: &Vec<Vec<usize>>) -> usize {
+        part1::solve(rucksacks)
+    }
+    fn part2(&self, rucksacks: &Vec<Vec<usize>>) -> usize {
+        part2::solve(rucksacks)
+    }
+}
@@ -1,31 +1,23 @@
 use std::collections::HashSet;
 
-pub fn run(input: &str) -> usize {
-    let priorities = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
+
+pub fn solve(rucksacks: &[Vec<usize>]) -> usize {
     let mut score: usize = 0;
 
-    for line in input.split('\n') {
-        let section_length = line.len() / 2;
-        let first_half: HashSet<char> = line[0 .. section_length].chars().into_iter().collect();
-        let second_half: HashSet<char> = line[section_length .. line.len()].chars().into_iter().collect();
-        score += first_half.intersection(&second_half)
-            .map(|item| match priorities.find(|c| &c == item) {
-                Some(val) => val + 1,
-                None => 0
-            })
-            .sum::<usize>();
+    for rucksack in rucksacks {
+        let section_length = rucksack.len() / 2;
+        let first_half: HashSet<&usize> = rucksack[0..section_length].iter().collect();
+        let second_half: HashSet<&usize> = rucksack[section_length..].iter().collect();
+        let shared = arith::sum(3, first_half.intersection(&second_half).copied().copied());
+        score = arith::add(3, score, shared);
     }
 
     score
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 157usize);
-    }
-}
\ No newline at end of file
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse_rucksacks(input)?))
+}
@@ -1,12 +1,17 @@
-use std::collections::HashSet;
 use itertools::Itertools;
+use std::collections::HashSet;
 
-pub fn run(input: &str) -> usize {
-    let priorities = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
+
+pub fn solve(rucksacks: &[Vec<usize>]) -> usize {
     let mut score: usize = 0;
 
-    let iter = input.split('\n').map(|i| i.chars().collect::<HashSet<char>>());
-    
+    let iter = rucksacks
+        .iter()
+        .map(|rucksack| rucksack.iter().copied().collect::<HashSet<usize>>());
+
     for (elf1, elf2, elf3) in iter.tuples() {
         let reduced = [elf2, elf3]
             .iter()
@@ -15,24 +20,13 @@ pub fn run(input: &str) -> usize {
         if badge.is_none() {
             break;
         }
-        let priority = priorities.find(|c| &c == badge.unwrap());
-                    
-        score += match priority {
-            Some(val) => val + 1,
-            None => 0
-        };
+
+        score = arith::add(3, score, *badge.unwrap());
     }
 
     score
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 70usize);
-    }
-}
\ No newline at end of file
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse_rucksacks(input)?))
+}
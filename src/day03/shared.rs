@@ -0,0 +1,27 @@
+use crate::parse::ParseError;
+
+/**
+ * Convert each rucksack's items into their priorities: a-z are 1 through 26, A-Z are 27 through 52.
+ */
+pub fn parse_rucksacks(input: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    let priorities = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    input
+        .split('\n')
+        .enumerate()
+        .map(|(index, line)| {
+            line.char_indices()
+                .map(|(offset, item)| match priorities.find(item) {
+                    Some(val) => Ok(val + 1),
+                    None => Err(ParseError::char_at(
+                        3,
+                        index + 1,
+                        line,
+                        offset,
+                        "an item from a-z or A-Z",
+                    )),
+                })
+                .collect()
+        })
+        .collect()
+}
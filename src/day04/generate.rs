@@ -0,0 +1,18 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * `size` pairs of section assignments between 1 and 99.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let [first, second] = [(); 2].map(|_| {
+                let start = rng.range(1..=99);
+                (start, rng.range(start..=99))
+            });
+            format!("{}-{},{}-{}", first.0, first.1, second.0, second.1)
+        })
+        .join("\n")
+}
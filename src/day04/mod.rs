@@ -0,0 +1,25 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Vec<shared::Pair>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<shared::Pair>, ParseError> {
+        shared::parse_pairs(input)
+    }
+    fn part1(&self, pairs: &Vec<shared::Pair>) -> usize {
+        part1::solve(pairs)
+    }
+    fn part2(&self, pairs: &Vec<shared::Pair>) -> usize {
+        part2::solve(pairs)
+    }
+}
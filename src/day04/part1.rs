@@ -1,23 +1,13 @@
-use itertools::Itertools;
+use super::shared::*;
+use crate::parse::ParseError;
 
-pub fn run(input: &str) -> usize {
+pub fn solve(pairs: &[Pair]) -> usize {
     let mut score: usize = 0;
 
-    for line in input.split('\n') {
-        let (pair1, pair2) = line
-            .split(',')
-            .map(|pair| 
-                pair
-                    .split('-')
-                    .map(|n| 
-                        n.parse::<usize>().unwrap()
-                    )
-                    .collect::<Vec<usize>>()
-            )
-            .next_tuple()
-            .unwrap();
-        if  pair2.iter().all(|endpoint| (pair1[0]..=pair1[1]).contains(endpoint)) || 
-            pair1.iter().all(|endpoint| (pair2[0]..=pair2[1]).contains(endpoint)) {
+    for (pair1, pair2) in pairs {
+        if (pair1.contains(pair2.start()) && pair1.contains(pair2.end()))
+            || (pair2.contains(pair1.start()) && pair2.contains(pair1.end()))
+        {
             score += 1;
         }
     }
@@ -25,13 +15,6 @@ pub fn run(input: &str) -> usize {
     score
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 2usize);
-    }
-}
\ No newline at end of file
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse_pairs(input)?))
+}
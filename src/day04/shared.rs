@@ -0,0 +1,44 @@
+use std::ops::RangeInclusive;
+
+use crate::parse::ParseError;
+
+/**
+ * A pair of section assignments, like `2-4,6-8`.
+ */
+pub type Pair = (RangeInclusive<usize>, RangeInclusive<usize>);
+
+/**
+ * Parse one section range like `2-4`, which appears in `line` (line `number` of the input).
+ */
+fn parse_range(
+    line: &str,
+    number: usize,
+    range: &str,
+) -> Result<RangeInclusive<usize>, ParseError> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::within(4, number, line, range, "a range like `2-4`"))?;
+    let start = start
+        .parse::<usize>()
+        .map_err(|_| ParseError::within(4, number, line, start, "a section number"))?;
+    let end = end
+        .parse::<usize>()
+        .map_err(|_| ParseError::within(4, number, line, end, "a section number"))?;
+    Ok(start..=end)
+}
+
+pub fn parse_pairs(input: &str) -> Result<Vec<Pair>, ParseError> {
+    input
+        .split('\n')
+        .enumerate()
+        .map(|(index, line)| {
+            let (first, second) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::line(4, index + 1, line, "two ranges like `2-4,6-8`"))?;
+            Ok((
+                parse_range(line, index + 1, first)?,
+                parse_range(line, index + 1, second)?,
+            ))
+        })
+        .collect()
+}
@@ -0,0 +1,51 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * Three to nine stacks of crates followed by `size` moves. Moves never empty a stack, so every
+ * stack has a crate on top at the end.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let count = rng.below(7) + 3;
+    let mut stacks = (0..count)
+        .map(|_| (0..=rng.below(8)).map(|_| item(rng)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    // with more crates than stacks, some stack always has a crate to spare
+    stacks[0].push(item(rng));
+
+    // draw the stacks from the top down, with every row padded to the full width
+    let tallest = stacks.iter().map(Vec::len).max().unwrap();
+    let mut lines = (0..tallest)
+        .rev()
+        .map(|level| {
+            stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(item) => format!("[{}]", item),
+                    None => "   ".to_string(),
+                })
+                .join(" ")
+        })
+        .collect::<Vec<_>>();
+    lines.push((1..=count).map(|name| format!(" {} ", name)).join(" "));
+    lines.push(String::new());
+
+    for _ in 0..size.max(1) {
+        let spare = (0..count)
+            .filter(|&stack| stacks[stack].len() > 1)
+            .collect::<Vec<_>>();
+        let from = *rng.pick(&spare);
+        let to = (from + rng.below(count - 1) + 1) % count;
+        let moved = rng.below(stacks[from].len() - 1) + 1;
+        let remaining = stacks[from].len() - moved;
+        let crates = stacks[from].split_off(remaining);
+        stacks[to].extend(crates);
+        lines.push(format!("move {} from {} to {}", moved, from + 1, to + 1));
+    }
+    lines.join("\n")
+}
+
+fn item(rng: &mut Rng) -> char {
+    (b'A' + rng.below(26) as u8) as char
+}
@@ -0,0 +1,30 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+use shared::{Move, Stacks};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = (Stacks, Vec<Move>);
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(&self, input: &str) -> Result<(Stacks, Vec<Move>), ParseError> {
+        shared::parse_input(input)
+    }
+    fn part1(&self, (stacks, moves): &(Stacks, Vec<Move>)) -> String {
+        part1::solve(stacks, moves)
+    }
+    fn part2(&self, (stacks, moves): &(Stacks, Vec<Move>)) -> String {
+        part2::solve(stacks, moves)
+    }
+    fn problems(&self, input: &str) -> Vec<ParseError> {
+        shared::input_problems(input)
+    }
+}
@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::parse::ParseError;
+
+/**
+ * The starting crate stacks, keyed by stack name. Each stack is listed bottom to top.
+ */
+#[derive(Debug, Clone)]
+pub struct Stacks {
+    pub names: Vec<char>,
+    pub stacks: HashMap<char, Vec<char>>,
+}
+
+impl Stacks {
+    /**
+     * The crate on top of each stack, in stack order. Empty stacks are skipped.
+     */
+    pub fn top_crates(&self) -> String {
+        self.names
+            .iter()
+            .filter_map(|stack| self.stacks.get(stack).unwrap().last())
+            .collect()
+    }
+}
+
+/**
+ * A command like `move 1 from 2 to 1`.
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct Move {
+    pub count: usize,
+    pub from: char,
+    pub to: char,
+}
+
+/**
+ * Parse the line naming the stacks, like ` 1   2   3 `, which is line `number` of the input. Each
+ * name is a single character, centered under its column of crates.
+ */
+fn parse_stack_names(number: usize, line: &str) -> Result<Vec<char>, ParseError> {
+    let mut names = vec![];
+    for (offset, name) in line.char_indices() {
+        if name.is_whitespace() {
+            continue;
+        }
+        if offset % 4 != 1
+            || line[offset..]
+                .chars()
+                .nth(1)
+                .is_some_and(|c| !c.is_whitespace())
+        {
+            let end = line[offset..]
+                .find(' ')
+                .map_or(line.len(), |end| offset + end);
+            return Err(ParseError::within(
+                5,
+                number,
+                line,
+                &line[offset..end],
+                "a single-character stack name",
+            ));
+        }
+        names.push(name);
+    }
+    Ok(names)
+}
+
+/**
+ * Read the stacks and the moves. Each move must take at most as many crates as its stack holds
+ * when the move is made.
+ */
+pub fn parse_input(input: &str) -> Result<(Stacks, Vec<Move>), ParseError> {
+    parse_all(input).map_err(|mut problems| problems.remove(0))
+}
+
+/**
+ * Every problem [`parse_input`] would stop at. If the stacks are fine, that's every move that's
+ * wrong, with each bad move skipped so later ones are checked against the stacks without it.
+ */
+pub fn input_problems(input: &str) -> Vec<ParseError> {
+    parse_all(input).err().unwrap_or_default()
+}
+
+fn parse_all(input: &str) -> Result<(Stacks, Vec<Move>), Vec<ParseError>> {
+    let (drawing, commands) = input.split_once("\n\n").ok_or_else(|| {
+        vec![ParseError::end_of_input(
+            5,
+            input.split('\n').count(),
+            "a blank line between the stacks and the moves",
+        )]
+    })?;
+    let stacks = parse_stacks(drawing).map_err(|e| vec![e])?;
+    // the moves start after the stacks and the blank line
+    let first_line = drawing.split('\n').count() + 2;
+    let moves = parse_moves(&stacks, first_line, commands)?;
+    Ok((stacks, moves))
+}
+
+/**
+ * Parse the crate drawing, ending with the line of stack names.
+ */
+fn parse_stacks(stacks: &str) -> Result<Stacks, ParseError> {
+    let rows = stacks.split('\n').collect::<Vec<_>>();
+    let names_line = rows[rows.len() - 1];
+    let stack_names = parse_stack_names(rows.len(), names_line)?;
+
+    let mut stacks_map: HashMap<char, Vec<char>> = HashMap::new();
+
+    // Initialize stacks
+    for stack in stack_names.clone() {
+        stacks_map.insert(stack, Vec::new());
+    }
+
+    // Populate stacks, each row's crates sitting in 4-character cells like `[A] `
+    for (index, row) in rows[..rows.len() - 1].iter().enumerate() {
+        let chars = row.char_indices().collect::<Vec<_>>();
+        for (position, cell) in chars.chunks(4).enumerate() {
+            let item = match cell {
+                [(_, '['), (_, item), (_, ']'), rest @ ..]
+                    if rest.iter().all(|(_, c)| *c == ' ') =>
+                {
+                    *item
+                }
+                _ if cell.iter().all(|(_, c)| *c == ' ') => continue,
+                _ => {
+                    let end = cell.get(3).map_or(row.len(), |&(end, _)| end);
+                    let text = &row[cell[0].0..end];
+                    return Err(ParseError::within(
+                        5,
+                        index + 1,
+                        row,
+                        text,
+                        "a crate like `[A]`",
+                    ));
+                }
+            };
+            let stack = stack_names.get(position).ok_or_else(|| {
+                let text = &row[cell[0].0..];
+                ParseError::within(5, index + 1, row, text, "a crate above a named stack")
+            })?;
+            stacks_map.get_mut(stack).unwrap().insert(0, item);
+        }
+    }
+
+    Ok(Stacks {
+        names: stack_names,
+        stacks: stacks_map,
+    })
+}
+
+/**
+ * Parse the moves, which start on line `first_line`, and make them on a count of each stack's
+ * crates, so a move that takes more crates than its stack holds at that point is an error.
+ */
+fn parse_moves(
+    stacks: &Stacks,
+    first_line: usize,
+    commands: &str,
+) -> Result<Vec<Move>, Vec<ParseError>> {
+    let mut heights = stacks
+        .stacks
+        .iter()
+        .map(|(&name, crates)| (name, crates.len()))
+        .collect::<HashMap<_, _>>();
+    let mut moves = vec![];
+    let mut problems = vec![];
+    for (index, command) in commands.split('\n').enumerate() {
+        let line = first_line + index;
+        let (count, parsed) = match parse_move(&stacks.names, line, command) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                problems.push(e);
+                continue;
+            }
+        };
+        let height = heights[&parsed.from];
+        if parsed.count > height {
+            problems.push(ParseError::within(
+                5,
+                line,
+                command,
+                count,
+                format!(
+                    "a crate count of at most {}, the height of stack {}",
+                    height, parsed.from
+                ),
+            ));
+            continue;
+        }
+        *heights.get_mut(&parsed.from).unwrap() -= parsed.count;
+        *heights.get_mut(&parsed.to).unwrap() += parsed.count;
+        moves.push(parsed);
+    }
+    if problems.is_empty() {
+        Ok(moves)
+    } else {
+        Err(problems)
+    }
+}
+
+/**
+ * Parse one move, which is line `line`, returning it with the text of its count.
+ */
+fn parse_move<'a>(
+    stack_names: &[char],
+    line: usize,
+    command: &'a str,
+) -> Result<(&'a str, Move), ParseError> {
+    let expected = "a move like `move 1 from 2 to 1`";
+    let (verb, count, from_word, from, to_word, to) = command
+        .split(' ')
+        .collect_tuple()
+        .ok_or_else(|| ParseError::line(5, line, command, expected))?;
+    if verb != "move" || from_word != "from" || to_word != "to" {
+        return Err(ParseError::line(5, line, command, expected));
+    }
+    let stack = |name: &str| {
+        let mut chars = name.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if stack_names.contains(&c) => Ok(c),
+            _ => Err(ParseError::within(5, line, command, name, "a stack name")),
+        }
+    };
+    let parsed = Move {
+        count: count
+            .parse()
+            .map_err(|_| ParseError::within(5, line, command, count, "a crate count"))?,
+        from: stack(from)?,
+        to: stack(to)?,
+    };
+    Ok((count, parsed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_too_many_crates() {
+        let sample = include_str!("inputs/sample.txt");
+        // stack 2 holds 3 crates, and the first move takes one
+        let input = sample.replace("move 3 from 1 to 3", "move 5 from 2 to 1");
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 5, line 7, column 6: expected a crate count of at most 2, the height of stack 2, \
+             found \"5\""
+        );
+
+        let input = sample
+            .replace("move 3 from 1 to 3", "move 9 from 1 to 3")
+            .replace("move 1 from 1 to 2", "move 1 from 2 to 1");
+        let problems = input_problems(&input);
+        assert_eq!(problems.iter().map(|e| e.line).collect::<Vec<_>>(), [7, 9]);
+        assert!(input_problems(sample).is_empty());
+    }
+}
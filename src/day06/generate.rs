@@ -0,0 +1,17 @@
+use crate::random::Rng;
+
+/**
+ * A datastream of `size` characters (at least 14), drawn from a few letters at a time so markers
+ * are rare, and ending in a start-of-message marker so one is always found.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let letters = "abcdefghijklmnopqrstuvwxyz".chars().collect::<Vec<_>>();
+    let length = size.max(14);
+    let mut stream = (0..length - 14)
+        .map(|i| letters[(i / 50 + rng.below(6)) % 26])
+        .collect::<String>();
+    let mut marker = letters.clone();
+    rng.shuffle(&mut marker);
+    stream.extend(&marker[..14]);
+    stream
+}
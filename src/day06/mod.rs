@@ -0,0 +1,25 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = Vec<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<char>, ParseError> {
+        shared::parse_signal(input)
+    }
+    fn part1(&self, signal: &Vec<char>) -> usize {
+        part1::solve(signal)
+    }
+    fn part2(&self, signal: &Vec<char>) -> usize {
+        part2::solve(signal)
+    }
+}
@@ -1,23 +1,20 @@
-use std::collections::HashSet;
+use std::io::BufRead;
 
-pub fn run(input: &str) -> usize {
-    let size = 4;
-    for (index, window) in input.chars().collect::<Vec<char>>().windows(size).enumerate() {
-        if window.iter().collect::<HashSet<&char>>().len() == size {
-            return index + size;
-        }
-    }
-    
-    0
+use super::shared::*;
+use crate::parse::ParseError;
+use crate::stream::StreamError;
+
+pub fn solve(signal: &[char]) -> usize {
+    find_marker(signal, 4)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse_signal(input)?))
+}
 
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 7usize);
-    }
-}
\ No newline at end of file
+/**
+ * Like [`run`], but reads the input as it goes instead of loading it all.
+ */
+pub fn run_reader(reader: impl BufRead) -> Result<usize, StreamError> {
+    find_marker_in(reader, 4)
+}
@@ -0,0 +1,136 @@
+use std::collections::{HashSet, VecDeque};
+use std::io::BufRead;
+
+use crate::parse::ParseError;
+use crate::stream::StreamError;
+
+/**
+ * Read the datastream buffer, which is a single line of lowercase letters.
+ */
+pub fn parse_signal(input: &str) -> Result<Vec<char>, ParseError> {
+    input
+        .char_indices()
+        .map(|(offset, c)| match c {
+            'a'..='z' => Ok(c),
+            _ => Err(ParseError::char_at(
+                6,
+                1,
+                input,
+                offset,
+                "a lowercase letter",
+            )),
+        })
+        .collect()
+}
+
+/**
+ * The last `size` letters of a datastream, with a count of each letter and how many appear more
+ * than once.
+ */
+struct Window {
+    size: usize,
+    letters: VecDeque<usize>,
+    counts: [usize; 26],
+    repeated: usize,
+}
+
+impl Window {
+    fn new(size: usize) -> Window {
+        Window {
+            size,
+            letters: VecDeque::with_capacity(size + 1),
+            counts: [0; 26],
+            repeated: 0,
+        }
+    }
+
+    /**
+     * Slide the window along to the next letter. Returns true if it's now a marker: full, with no
+     * letter repeated.
+     */
+    fn push(&mut self, c: char) -> bool {
+        let letter = c as usize - 'a' as usize;
+        self.letters.push_back(letter);
+        self.counts[letter] += 1;
+        if self.counts[letter] == 2 {
+            self.repeated += 1;
+        }
+        if self.letters.len() > self.size {
+            let dropped = self.letters.pop_front().unwrap();
+            self.counts[dropped] -= 1;
+            if self.counts[dropped] == 1 {
+                self.repeated -= 1;
+            }
+        }
+        self.letters.len() == self.size && self.repeated == 0
+    }
+}
+
+/**
+ * Find the number of characters read before the end of the first window of `size` distinct characters.
+ */
+pub fn find_marker(signal: &[char], size: usize) -> usize {
+    let mut window = Window::new(size);
+    for (index, &c) in signal.iter().enumerate() {
+        if window.push(c) {
+            return index + 1;
+        }
+    }
+
+    0
+}
+
+/**
+ * Like [`find_marker`], but reads the datastream from `reader` as it goes, keeping only the last
+ * `size` letters. The rest of the datastream after the marker is still read and checked, so a bad
+ * letter anywhere is an error, just as it is for [`parse_signal`]. Line endings are allowed at the
+ * end of the datastream.
+ */
+pub fn find_marker_in(mut reader: impl BufRead, size: usize) -> Result<usize, StreamError> {
+    let mut window = Window::new(size);
+    let mut marker = None;
+    let mut read = 0;
+    let mut ended = false;
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            return Ok(marker.unwrap_or(0));
+        }
+        for &byte in buffer {
+            match byte {
+                b'a'..=b'z' if !ended => {
+                    read += 1;
+                    if marker.is_none() && window.push(byte as char) {
+                        marker = Some(read);
+                    }
+                }
+                b'\n' | b'\r' => ended = true,
+                _ => {
+                    let text = String::from_utf8_lossy(&[byte]).into_owned();
+                    let expected = if ended {
+                        "the end of the datastream"
+                    } else {
+                        "a lowercase letter"
+                    };
+                    return Err(ParseError::new(6, 1, read + 1, text, expected).into());
+                }
+            }
+        }
+        let length = buffer.len();
+        reader.consume(length);
+    }
+}
+
+/**
+ * Reference version of [`find_marker`]: collects every window into a set to count its distinct
+ * characters.
+ */
+pub fn find_marker_naive(signal: &[char], size: usize) -> usize {
+    for (index, window) in signal.windows(size).enumerate() {
+        if window.iter().collect::<HashSet<&char>>().len() == size {
+            return index + size;
+        }
+    }
+
+    0
+}
@@ -0,0 +1,91 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+struct Directory {
+    name: String,
+    files: Vec<(String, usize)>,
+    children: Vec<Directory>,
+}
+
+const NAMES: [&str; 12] = ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l"];
+const EXTENSIONS: [&str; 5] = ["", ".txt", ".dat", ".log", ".lst"];
+
+/**
+ * A shell transcript exploring a filesystem of `size` directories. The files add up to between
+ * 40,000,000 and 70,000,000 bytes, so part 2 always has something to delete.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut root = Directory {
+        name: "/".to_string(),
+        files: vec![],
+        children: vec![],
+    };
+    // add directories one at a time under random existing ones
+    for _ in 1..size.max(1) {
+        let mut directory = &mut root;
+        while !directory.children.is_empty() && rng.chance(0.6) {
+            let index = rng.below(directory.children.len());
+            directory = &mut directory.children[index];
+        }
+        if directory.children.len() < NAMES.len() {
+            let name = NAMES[directory.children.len()].repeat(rng.below(3) + 1);
+            directory.children.push(Directory {
+                name,
+                files: vec![],
+                children: vec![],
+            });
+        }
+    }
+
+    let mut total = 0;
+    fill(rng, &mut root, &mut total);
+    let target = rng.range(40_000_001..=69_000_000) as usize;
+    if total < target {
+        root.files.push(("padding.dat".to_string(), target - total));
+    }
+
+    let mut lines = vec![];
+    transcript(&root, &mut lines);
+    lines.join("\n")
+}
+
+/**
+ * Give each directory a few files, keeping the total under 40,000,000 bytes.
+ */
+fn fill(rng: &mut Rng, directory: &mut Directory, total: &mut usize) {
+    for name in NAMES.iter().take(rng.below(5)) {
+        let size = rng.range(1000..=300_000) as usize;
+        if *total + size > 40_000_000 {
+            break;
+        }
+        *total += size;
+        let name = format!("{}{}", name.repeat(4), rng.pick(&EXTENSIONS));
+        directory.files.push((name, size));
+    }
+    for child in &mut directory.children {
+        fill(rng, child, total);
+    }
+}
+
+fn transcript(directory: &Directory, lines: &mut Vec<String>) {
+    lines.push(format!("$ cd {}", directory.name));
+    lines.push("$ ls".to_string());
+    lines.extend(
+        directory
+            .children
+            .iter()
+            .map(|child| format!("dir {}", child.name))
+            .chain(
+                directory
+                    .files
+                    .iter()
+                    .map(|(name, size)| format!("{} {}", size, name)),
+            )
+            .sorted(),
+    );
+    for child in &directory.children {
+        transcript(child, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
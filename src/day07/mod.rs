@@ -0,0 +1,41 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::trace::Tracer;
+
+use shared::Node;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Node;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Node, ParseError> {
+        shared::parse_commands(input)
+    }
+    fn part1(&self, directories: &Node) -> usize {
+        part1::solve(directories)
+    }
+    fn part2(&self, directories: &Node) -> usize {
+        part2::solve(directories)
+    }
+}
+
+/**
+ * Show how an answer was reached: part 1 lists the directories that were summed, part 2 the space
+ * that has to be freed and the directory deleted to free it.
+ */
+pub fn explain(input: &str, part: u8, tracer: &mut dyn Tracer) -> Result<String, ParseError> {
+    let directories = shared::parse_commands(input)?;
+    let answer = match part {
+        1 => part1::solve_explained(&directories, tracer),
+        _ => part2::solve_explained(&directories, tracer),
+    };
+    Ok(answer.to_string())
+}
@@ -1,4 +1,7 @@
 use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
+use crate::trace::{NoTracer, Step, Tracer};
 
 /**
  * Given a node:
@@ -6,33 +9,49 @@ use super::shared::*;
  * 2. For each child, recursively call this function.
  * 3. Return the sum.
  */
-fn find_directories(node: &Node) -> usize {
+fn find_directories<'a>(
+    node: &'a Node,
+    names: &mut Vec<&'a str>,
+    tracer: &mut dyn Tracer,
+) -> usize {
     let mut sum = 0;
     if node.size == 0 {
         let size = get_size(node);
         if size < 100000 {
-            sum += size;
+            sum = arith::add(7, sum, size);
+            tracer.emit(|| {
+                Step::new("counted directory")
+                    .with("path", path_of(names))
+                    .with("size", size)
+            });
         }
     }
     for child in &node.children {
-        sum += find_directories(child);
+        // the names are only needed for the steps, so don't collect them otherwise
+        let traced = tracer.enabled();
+        if traced {
+            names.push(&child.name);
+        }
+        let size = find_directories(child, names, tracer);
+        if traced {
+            names.pop();
+        }
+        sum = arith::add(7, sum, size);
     }
     sum
 }
 
-pub fn run(input: &str) -> usize {
-    let directories = parse_commands(input);
-
-    find_directories(&directories)
+pub fn solve(directories: &Node) -> usize {
+    solve_explained(directories, &mut NoTracer)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/**
+ * Like [`solve`], sending each directory that counts towards the sum to `tracer`.
+ */
+pub fn solve_explained(directories: &Node, tracer: &mut dyn Tracer) -> usize {
+    find_directories(directories, &mut vec![], tracer)
+}
 
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 95437usize);
-    }
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse_commands(input)?))
 }
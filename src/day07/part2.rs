@@ -1,10 +1,13 @@
 use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
+use crate::trace::{NoTracer, Step, Tracer};
 
 /**
  * Walk the tree and find the smallest directory that is greater than space_available
  */
 fn find_directories(node: &Node, space_needed: usize) -> usize {
-    let mut min = usize::max_value();
+    let mut min = usize::MAX;
     if node.size == 0 {
         let size = get_size(node);
         if size >= space_needed && size < min {
@@ -20,21 +23,46 @@ fn find_directories(node: &Node, space_needed: usize) -> usize {
     min
 }
 
-pub fn run(input: &str) -> usize {
-    let directories = parse_commands(input);
-
-    let space_needed = 30000000 - (70000000 - get_size(&directories));
+/**
+ * The path of the first directory of `size` below `node`, which is reached through `names`.
+ */
+fn find_path<'a>(node: &'a Node, names: &mut Vec<&'a str>, size: usize) -> Option<String> {
+    if node.size == 0 && get_size(node) == size {
+        return Some(path_of(names));
+    }
+    node.children.iter().find_map(|child| {
+        names.push(&child.name);
+        let path = find_path(child, names, size);
+        names.pop();
+        path
+    })
+}
 
-    find_directories(&directories, space_needed)
+pub fn solve(directories: &Node) -> usize {
+    solve_explained(directories, &mut NoTracer)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/**
+ * Like [`solve`], sending the space that has to be freed and the directory chosen to `tracer`.
+ */
+pub fn solve_explained(directories: &Node, tracer: &mut dyn Tracer) -> usize {
+    let used = get_size(directories);
+    let space_needed = arith::sub(7, 30000000, arith::sub(7, 70000000, used));
+    tracer.emit(|| {
+        Step::new("space to free")
+            .with("used", used)
+            .with("needed", space_needed)
+    });
 
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 24933642usize);
-    }
+    let size = find_directories(directories, space_needed);
+    tracer.emit(|| {
+        Step::new("deleted directory")
+            .with("path", find_path(directories, &mut vec![], size))
+            .with("size", size)
+    });
+    size
+}
+
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse_commands(input)?))
 }
@@ -1,5 +1,8 @@
 use std::borrow::BorrowMut;
 
+use crate::arith;
+use crate::parse::ParseError;
+
 // Struct representing a filesystem node
 #[derive(Debug)]
 pub struct Node {
@@ -13,18 +16,27 @@ pub struct Node {
  *
  * Folders will have the format `dir [a]`, where `a` is the name of the folder.
  * Files will have the format `[100] [b]`, where `100` is the size of the file and `b` is the name of the file.
+ *
+ * `line` is the line number of `command` in the input, for error reporting.
  */
-pub fn parse_node(command: &str, root: &mut Node) {
-    let mut parts = command.split_whitespace();
+pub fn parse_node(command: &str, line: usize, root: &mut Node) -> Result<(), ParseError> {
+    let (kind, name) = command
+        .split_once(' ')
+        .ok_or_else(|| ParseError::line(7, line, command, "`dir [name]` or `[size] [name]`"))?;
     // if the first segment is a number, then it's a file; directories have size 0
-    let size = parts.next().unwrap().parse::<usize>().unwrap_or(0);
-    let name = parts.next().unwrap().to_string();
+    let size = match kind {
+        "dir" => 0,
+        _ => kind
+            .parse::<usize>()
+            .map_err(|_| ParseError::within(7, line, command, kind, "`dir` or a file size"))?,
+    };
     let node = Node {
-        name,
+        name: name.to_string(),
         children: vec![],
         size,
     };
     root.children.push(node);
+    Ok(())
 }
 
 /**
@@ -38,7 +50,7 @@ pub fn parse_node(command: &str, root: &mut Node) {
  *
  * Return the root node.
  */
-pub fn parse_commands(commands: &str) -> Node {
+pub fn parse_commands(commands: &str) -> Result<Node, ParseError> {
     let mut root = Node {
         name: "".to_string(),
         children: vec![Node {
@@ -48,16 +60,35 @@ pub fn parse_commands(commands: &str) -> Node {
         }],
         size: 0,
     };
-    let mut stack = vec![];
-    for line in commands.lines() {
-        if line.starts_with("$ cd ") {
-            let folder = line.split_whitespace().nth(2).unwrap();
+    let mut stack: Vec<&str> = vec![];
+    for (index, line) in commands.lines().enumerate() {
+        if let Some(folder) = line.strip_prefix("$ cd ") {
             if folder == ".." {
                 stack.pop();
             } else {
-                stack.push(folder.to_string());
+                // `cd` can only enter a folder that has already been listed
+                let mut current = &root;
+                for name in stack.iter().chain([&folder]) {
+                    current = current
+                        .children
+                        .iter()
+                        .find(|node| node.name == *name && node.size == 0)
+                        .ok_or_else(|| {
+                            ParseError::within(7, index + 1, line, folder, "a listed directory")
+                        })?;
+                }
+                stack.push(folder);
             }
-        } else if !line.starts_with('$') {
+        } else if line == "$ ls" {
+            continue;
+        } else if line.starts_with('$') {
+            return Err(ParseError::line(
+                7,
+                index + 1,
+                line,
+                "`$ cd [dir]` or `$ ls`",
+            ));
+        } else {
             let mut current = &mut root;
             for folder in stack.iter() {
                 current = current
@@ -66,15 +97,30 @@ pub fn parse_commands(commands: &str) -> Node {
                     .find(|node| node.name == *folder)
                     .unwrap();
             }
-            parse_node(line, current.borrow_mut());
+            parse_node(line, index + 1, current.borrow_mut())?;
         }
     }
-    root
+    Ok(root)
 }
 
 /**
  * Given a node, return the size of the node and all of its children.
  */
 pub fn get_size(node: &Node) -> usize {
-    node.size + node.children.iter().map(get_size).sum::<usize>()
+    arith::add(
+        7,
+        node.size,
+        arith::sum(7, node.children.iter().map(get_size)),
+    )
+}
+
+/**
+ * The path of the node reached through the directories `names` below the root node, like `/a/e`
+ * for `["/", "a", "e"]`. The root node itself has an empty path.
+ */
+pub fn path_of(names: &[&str]) -> String {
+    match names {
+        [] => String::new(),
+        [top, rest @ ..] => format!("{}{}", top, rest.join("/")),
+    }
 }
@@ -0,0 +1,15 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * A `size` by `size` grid of tree heights.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| (0..size).map(|_| rng.pick(&DIGITS)).collect::<String>())
+        .join("\n")
+}
+
+const DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
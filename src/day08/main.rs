@@ -1,9 +1,11 @@
-mod part1;
-mod part2;
+use aoc::day08::{part1, part2};
+use aoc::parse::ParseError;
 
-fn main() {
+fn main() -> Result<(), ParseError> {
     let input = include_str!("inputs/input.txt");
 
-    println!("Part 1: {}", part1::run(input));
-    println!("Part 2: {}", part2::run(input));
+    println!("Part 1: {}", part1::run(input)?);
+    println!("Part 2: {}", part2::run(input)?);
+
+    Ok(())
 }
@@ -0,0 +1,26 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = Grid<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Grid<usize>, ParseError> {
+        shared::parse_grid(input)
+    }
+    fn part1(&self, grid: &Grid<usize>) -> usize {
+        part1::solve(grid)
+    }
+    fn part2(&self, grid: &Grid<usize>) -> usize {
+        part2::solve(grid)
+    }
+}
@@ -1,53 +1,73 @@
+use super::shared::*;
+use crate::grid::{Grid, Position};
+use crate::parse::ParseError;
+
 /**
  * Given a grid of numbers, a grid square is "visible" if all other squares to the top, left, right, and down are less than it.
  *
  * Given a coordinate, check if it is not visible.
  */
-pub fn check_visible(grid: &[Vec<usize>], x: usize, y: usize) -> bool {
-    let num = grid[y][x];
-    if grid[0..y].iter().all(|row| row[x] < num) {
+pub fn check_visible(grid: &Grid<usize>, x: usize, y: usize) -> bool {
+    let num = grid[(x, y)];
+    let row = grid.row(y);
+    if grid.column(x).take(y).all(|square| square < &num) {
         return true;
     }
-    if grid[y][0..x].iter().all(|square| square < &num) {
+    if row[0..x].iter().all(|square| square < &num) {
         return true;
     }
-    if grid[y + 1..].iter().all(|row| row[x] < num) {
+    if grid.column(x).skip(y + 1).all(|square| square < &num) {
         return true;
     }
-    if grid[y][x + 1..].iter().all(|square| square < &num) {
+    if row[x + 1..].iter().all(|square| square < &num) {
         return true;
     }
     false
 }
 
-pub fn run(input: &str) -> usize {
-    let grid = input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|num| num.to_digit(10).unwrap().try_into().unwrap())
-                .collect::<Vec<usize>>()
-        })
-        .collect::<Vec<Vec<usize>>>();
+/**
+ * Mark every tree that is visible from outside the grid, by looking along each row and column from
+ * both ends and keeping track of the tallest tree seen so far.
+ */
+pub fn visible_trees(grid: &Grid<usize>) -> Grid<bool> {
+    let mut visible = Grid::new(grid.width(), grid.height(), false);
+    for y in 0..grid.height() {
+        look_along(grid, &mut visible, (0..grid.width()).map(|x| (x, y)));
+        look_along(grid, &mut visible, (0..grid.width()).rev().map(|x| (x, y)));
+    }
+    for x in 0..grid.width() {
+        look_along(grid, &mut visible, (0..grid.height()).map(|y| (x, y)));
+        look_along(grid, &mut visible, (0..grid.height()).rev().map(|y| (x, y)));
+    }
+    visible
+}
 
-    let mut visible = 0;
-    for (y, row) in grid.iter().enumerate() {
-        for (x, _) in row.iter().enumerate() {
-            if check_visible(&grid, x, y) {
-                visible += 1;
-            }
+fn look_along(grid: &Grid<usize>, visible: &mut Grid<bool>, line: impl Iterator<Item = Position>) {
+    let mut tallest = None;
+    for position in line {
+        if tallest.is_none_or(|tallest| grid[position] > tallest) {
+            visible[position] = true;
+            tallest = Some(grid[position]);
         }
     }
-    visible
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub fn solve(grid: &Grid<usize>) -> usize {
+    visible_trees(grid)
+        .iter()
+        .filter(|(_, &visible)| visible)
+        .count()
+}
 
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 21usize);
-    }
+/**
+ * Reference version of [`solve`], which checks each tree with [`check_visible`].
+ */
+pub fn solve_naive(grid: &Grid<usize>) -> usize {
+    grid.positions()
+        .filter(|&(x, y)| check_visible(grid, x, y))
+        .count()
+}
+
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse_grid(input)?))
 }
@@ -0,0 +1,21 @@
+use crate::grid::Grid;
+use crate::parse::ParseError;
+
+/**
+ * Parse the map of tree heights, one digit per tree.
+ */
+pub fn parse_grid(input: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::parse(input.lines(), |(x, y), num| {
+        num.to_digit(10)
+            .map(|height| height as usize)
+            .ok_or_else(|| {
+                ParseError::new(
+                    8,
+                    y + 1,
+                    x + 1,
+                    num.to_string(),
+                    "a tree height from 0 to 9",
+                )
+            })
+    })
+}
@@ -0,0 +1,12 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * `size` head motions of 1 to 20 steps each.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| format!("{} {}", rng.pick(&['R', 'L', 'U', 'D']), rng.range(1..=20)))
+        .join("\n")
+}
@@ -0,0 +1,40 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::visualize::Visualizer;
+
+use shared::Direction;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = Vec<(Direction, usize)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<(Direction, usize)>, ParseError> {
+        shared::parse_moves(input)
+    }
+    fn part1(&self, moves: &Vec<(Direction, usize)>) -> usize {
+        part1::solve(moves)
+    }
+    fn part2(&self, moves: &Vec<(Direction, usize)>) -> usize {
+        part2::solve(moves)
+    }
+}
+
+/**
+ * Watch the rope follow the moves; part 1 has two knots and part 2 has ten.
+ */
+pub fn visualize(input: &str, part: u8, visualizer: &mut dyn Visualizer) -> Result<(), ParseError> {
+    let moves = shared::parse_moves(input)?;
+    match part {
+        1 => part1::solve_with(&moves, visualizer),
+        _ => part2::solve_with(&moves, visualizer),
+    };
+    Ok(())
+}
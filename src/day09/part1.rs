@@ -1,42 +1,38 @@
-use itertools::Itertools;
 use std::collections::HashSet;
 
 use super::shared::*;
+use crate::parse::ParseError;
+use crate::visualize::{Frame, NoVisualizer, Visualizer};
 
 /**
- * Given a series of commands such as "R 4", map the direction (R = Direction.Right) and the distance (4) and iteratively apply the simulate_step function.
+ * Given a series of moves, iteratively apply the simulate_step function.
  */
-pub fn run(input: &str) -> usize {
-    let mut head = (0, 0);
-    let mut tail = (0, 0);
+pub fn solve(moves: &[(Direction, usize)]) -> usize {
+    solve_with(moves, &mut NoVisualizer)
+}
+
+/**
+ * Solve, sending a frame of the rope after each move.
+ */
+pub fn solve_with(moves: &[(Direction, usize)], visualizer: &mut dyn Visualizer) -> usize {
+    let mut head = Knot::default();
+    let mut tail = Knot::default();
     let mut visited = HashSet::new();
-    for line in input.lines() {
-        let (dir, distance) = line.split_whitespace().collect_tuple().unwrap();
-        for _ in 0..distance.parse::<usize>().unwrap() {
-            (head, tail) = simulate_step(
-                head,
-                tail,
-                match dir {
-                    "R" => Direction::Right,
-                    "L" => Direction::Left,
-                    "U" => Direction::Up,
-                    "D" => Direction::Down,
-                    _ => panic!("Invalid direction"),
-                },
-            );
+    for (index, (dir, distance)) in moves.iter().enumerate() {
+        for _ in 0..*distance {
+            (head, tail) = simulate_step(head, tail, *dir);
             visited.insert(tail);
         }
+        visualizer.emit(|| {
+            Frame::new(
+                format!("Move {}: {:?} {}", index + 1, dir, distance),
+                render_rope(&[head, tail], &visited),
+            )
+        });
     }
     visited.len()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 13usize);
-    }
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse_moves(input)?))
 }
@@ -1,43 +1,38 @@
-use itertools::Itertools;
 use std::collections::HashSet;
 
 use super::shared::*;
+use crate::parse::ParseError;
+use crate::visualize::{Frame, NoVisualizer, Visualizer};
 
 /**
- * Given a series of commands such as "R 4", map the direction (R = Direction.Right) and the distance (4) and iteratively apply the simulate_step function.
+ * Given a series of moves, iteratively apply the simulate_rope function.
  */
-pub fn run(input: &str) -> usize {
+pub fn solve(moves: &[(Direction, usize)]) -> usize {
+    solve_with(moves, &mut NoVisualizer)
+}
+
+/**
+ * Solve, sending a frame of the rope after each move.
+ */
+pub fn solve_with(moves: &[(Direction, usize)], visualizer: &mut dyn Visualizer) -> usize {
     let mut rope = vec![];
-    rope.resize(10, (0, 0));
+    rope.resize(10, Knot::default());
     let mut visited = HashSet::new();
-    for line in input.lines() {
-        let (dir, distance) = line.split_whitespace().collect_tuple().unwrap();
-        for _ in 0..distance.parse::<usize>().unwrap() {
-            rope = simulate_rope(
-                rope,
-                match dir {
-                    "R" => Direction::Right,
-                    "L" => Direction::Left,
-                    "U" => Direction::Up,
-                    "D" => Direction::Down,
-                    _ => panic!("Invalid direction"),
-                },
-            );
+    for (index, (dir, distance)) in moves.iter().enumerate() {
+        for _ in 0..*distance {
+            rope = simulate_rope(rope, *dir);
             visited.insert(rope[9]);
         }
+        visualizer.emit(|| {
+            Frame::new(
+                format!("Move {}: {:?} {}", index + 1, dir, distance),
+                render_rope(&rope, &visited),
+            )
+        });
     }
     visited.len()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 1usize);
-        let demo2 = include_str!("inputs/sample2.txt");
-        assert_eq!(run(demo2), 36usize);
-    }
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse_moves(input)?))
 }
@@ -1,59 +1,31 @@
-use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+pub use crate::geometry::Direction;
+use crate::geometry::Point2;
+use crate::parse::ParseError;
 
 /**
- * Return the Chebyshev distance between two points.
+ * A knot's position, with y increasing downwards.
  */
-fn chebyshev_distance((x1, y1): (i32, i32), (x2, y2): (i32, i32)) -> i32 {
-    (x1 - x2).abs().max((y1 - y2).abs())
-}
-
-// Direction enum
-#[derive(Debug, Copy, Clone)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+pub type Knot = Point2<i32>;
 
 /**
  * Given the position of head H and tail T, and a direction D, move the head in that direction and move the tail if its chebyshev distance from the head is greater than 1.
  */
-pub fn simulate_step(
-    (h_x, h_y): (i32, i32),
-    (t_x, t_y): (i32, i32),
-    d: Direction,
-) -> ((i32, i32), (i32, i32)) {
-    let (h_x, h_y) = match d {
-        Direction::Up => (h_x, h_y - 1),
-        Direction::Down => (h_x, h_y + 1),
-        Direction::Left => (h_x - 1, h_y),
-        Direction::Right => (h_x + 1, h_y),
-    };
-    follow_the_leader((h_x, h_y), (t_x, t_y))
+pub fn simulate_step(head: Knot, tail: Knot, d: Direction) -> (Knot, Knot) {
+    follow_the_leader(head.step(d), tail)
 }
 
 /**
  * Given the position of head H and tail T, move the tail if its chebyshev distance from the head is greater than 1.
  */
-pub fn follow_the_leader(
-    (h_x, h_y): (i32, i32),
-    (t_x, t_y): (i32, i32),
-) -> ((i32, i32), (i32, i32)) {
-    if chebyshev_distance((h_x, h_y), (t_x, t_y)) <= 1 {
-        return ((h_x, h_y), (t_x, t_y));
+pub fn follow_the_leader(head: Knot, tail: Knot) -> (Knot, Knot) {
+    if head.chebyshev(tail) <= 1 {
+        return (head, tail);
     }
-    let t_x = match h_x.cmp(&t_x) {
-        Ordering::Greater => t_x + 1,
-        Ordering::Less => t_x - 1,
-        Ordering::Equal => t_x,
-    };
-    let t_y = match h_y.cmp(&t_y) {
-        Ordering::Greater => t_y + 1,
-        Ordering::Less => t_y - 1,
-        Ordering::Equal => t_y,
-    };
-    ((h_x, h_y), (t_x, t_y))
+    (head, tail + (head - tail).signum())
 }
 
 /**
@@ -61,18 +33,10 @@ pub fn follow_the_leader(
  * in that direction and move each successive point if its chebyshev distance
  * from the preceeding is greater than 1.
  */
-pub fn simulate_rope(rope: Vec<(i32, i32)>, d: Direction) -> Vec<(i32, i32)> {
+pub fn simulate_rope(rope: Vec<Knot>, d: Direction) -> Vec<Knot> {
     let mut new_rope = vec![];
-    let (h_x, h_y) = rope[0];
-    let (h_x, h_y) = match d {
-        Direction::Up => (h_x, h_y - 1),
-        Direction::Down => (h_x, h_y + 1),
-        Direction::Left => (h_x - 1, h_y),
-        Direction::Right => (h_x + 1, h_y),
-    };
-    new_rope.push((h_x, h_y));
-
-    let mut head = (h_x, h_y);
+    let mut head = rope[0].step(d);
+    new_rope.push(head);
     rope.iter().skip(1).for_each(|tail| {
         let (_, new_tail) = follow_the_leader(head, *tail);
         new_rope.push(new_tail);
@@ -80,3 +44,58 @@ pub fn simulate_rope(rope: Vec<(i32, i32)>, d: Direction) -> Vec<(i32, i32)> {
     });
     new_rope
 }
+
+/**
+ * Render the part of the grid around the head: `H` for the head, `T` for the tail of a two-knot
+ * rope or the knot's number for a longer one, `s` for the start and `#` for cells the tail has
+ * visited.
+ */
+pub fn render_rope(rope: &[Knot], visited: &HashSet<Knot>) -> String {
+    const WIDTH: i32 = 61;
+    const HEIGHT: i32 = 31;
+    let corner = rope[0] - Knot::new(WIDTH / 2, HEIGHT / 2);
+    (0..HEIGHT)
+        .map(|y| {
+            (0..WIDTH)
+                .map(|x| {
+                    let cell = corner + Knot::new(x, y);
+                    match rope.iter().position(|knot| *knot == cell) {
+                        Some(0) => 'H',
+                        Some(_) if rope.len() == 2 => 'T',
+                        Some(i) => char::from_digit(i as u32, 36).unwrap_or('+'),
+                        None if cell == Knot::default() => 's',
+                        None if visited.contains(&cell) => '#',
+                        None => '.',
+                    }
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+/**
+ * Parse a series of commands such as "R 4" into a direction (R = Direction.Right) and a distance (4).
+ */
+pub fn parse_moves(input: &str) -> Result<Vec<(Direction, usize)>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let (dir, distance) = line
+                .split_whitespace()
+                .collect_tuple()
+                .ok_or_else(|| ParseError::line(9, index + 1, line, "a move like `R 4`"))?;
+            let dir = match dir {
+                "R" => Direction::Right,
+                "L" => Direction::Left,
+                "U" => Direction::Up,
+                "D" => Direction::Down,
+                _ => return Err(ParseError::within(9, index + 1, line, dir, "R, L, U or D")),
+            };
+            let distance = distance
+                .parse::<usize>()
+                .map_err(|_| ParseError::within(9, index + 1, line, distance, "a distance"))?;
+            Ok((dir, distance))
+        })
+        .collect()
+}
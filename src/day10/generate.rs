@@ -0,0 +1,23 @@
+use crate::random::Rng;
+
+/**
+ * A program of at least `size` instructions that runs for at least 240 cycles, so the whole CRT
+ * is drawn. `addx` keeps the register between -10 and 50.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+    let mut cycles = 0;
+    let mut x = 1;
+    while lines.len() < size || cycles < 240 {
+        if rng.chance(0.3) {
+            lines.push("noop".to_string());
+            cycles += 1;
+        } else {
+            let change = rng.range((-10 - x).max(-15)..=(50 - x).min(15));
+            x += change;
+            lines.push(format!("addx {}", change));
+            cycles += 2;
+        }
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,25 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>, ParseError> {
+        shared::parse_cycles(input)
+    }
+    fn part1(&self, cycles: &Vec<i32>) -> i32 {
+        part1::solve(cycles)
+    }
+    fn part2(&self, cycles: &Vec<i32>) -> String {
+        part2::solve(cycles)
+    }
+}
@@ -1,30 +1,41 @@
+use std::io::BufRead;
+
 use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
+use crate::stream::StreamError;
 
 /**
  * Given a series of commands, generate a Vec<usize> of the state of register `x` at each cycle.
  * The command `addx 1` will take two cycles to complete, and will add 1 to register `x` on the second cycle.
  * The command `noop` will take one cycle to complete, and will have no other effect.
  */
-pub fn run(input: &str) -> i32 {
-    let cycles = parse_cycles(input);
-
+pub fn solve(cycles: &[i32]) -> i32 {
     // return the 20th item and every 40th item after that
-    cycles
+    let strengths = cycles
         .iter()
         .enumerate()
         .skip(19)
         .step_by(40)
-        .map(|(i, cycle)| -> i32 { ((i as i32) + 1) * cycle })
-        .sum()
+        .map(|(i, &cycle)| arith::mul(10, i as i32 + 1, cycle));
+    arith::sum(10, strengths)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub fn run(input: &str) -> Result<i32, ParseError> {
+    Ok(solve(&parse_cycles(input)?))
+}
 
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 13140i32);
-    }
+/**
+ * Like [`run`], but runs the program as it's read, adding up the signal strengths on the way.
+ */
+pub fn run_reader(reader: impl BufRead) -> Result<i32, StreamError> {
+    let mut cycle = 0;
+    let mut strength = 0;
+    for_each_cycle(reader, |x| {
+        cycle += 1;
+        if cycle % 40 == 20 {
+            strength = arith::add(10, strength, arith::mul(10, cycle, x));
+        }
+    })?;
+    Ok(strength)
 }
@@ -1,4 +1,8 @@
+use std::io::BufRead;
+
 use super::shared::*;
+use crate::parse::ParseError;
+use crate::stream::StreamError;
 use itertools::Itertools;
 
 /**
@@ -6,9 +10,9 @@ use itertools::Itertools;
  * The command `addx 1` will take two cycles to complete, and will add 1 to register `x` on the second cycle.
  * The command `noop` will take one cycle to complete, and will have no other effect.
  */
-pub fn run(input: &str) -> String {
-    let mut cycles = vec![1];
-    cycles.extend(parse_cycles(input).iter());
+pub fn solve(cycles: &[i32]) -> String {
+    let mut cycles = cycles.to_vec();
+    cycles.insert(0, 1);
 
     // return the 20th item and every 40th item after that
     let chunks = cycles
@@ -30,25 +34,26 @@ pub fn run(input: &str) -> String {
         .join("\n")
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub fn run(input: &str) -> Result<String, ParseError> {
+    Ok(solve(&parse_cycles(input)?))
+}
 
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(
-            run(demo),
-            r"
-##..##..##..##..##..##..##..##..##..##..
-###...###...###...###...###...###...###.
-####....####....####....####....####....
-#####.....#####.....#####.....#####.....
-######......######......######......####
-#######.......#######.......#######.....
-            "
-            .trim()
-            .to_string()
-        );
-    }
+/**
+ * Like [`run`], but runs the program as it's read, drawing each row of the CRT as it goes.
+ */
+pub fn run_reader(reader: impl BufRead) -> Result<String, StreamError> {
+    let mut rows = vec![];
+    let mut row = String::new();
+    for_each_cycle(reader, |x| {
+        let pixel = row.len() as i32;
+        row.push(if ((x - 1)..=(x + 1)).contains(&pixel) {
+            '#'
+        } else {
+            '.'
+        });
+        if row.len() == 40 {
+            rows.push(std::mem::take(&mut row));
+        }
+    })?;
+    Ok(rows.join("\n"))
 }
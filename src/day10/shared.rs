@@ -1,21 +1,66 @@
-pub fn parse_cycles(input: &str) -> Vec<i32> {
+use std::io::BufRead;
+
+use crate::arith;
+use crate::parse::ParseError;
+use crate::stream::{for_each_line, StreamError};
+
+pub fn parse_cycles(input: &str) -> Result<Vec<i32>, ParseError> {
     let mut cycles: Vec<i32> = vec![1];
 
-    for line in input.lines() {
-        let mut words = line.split_whitespace();
-        let command = words.next().unwrap();
+    for (index, line) in input.lines().enumerate() {
+        let x = cycles[cycles.len() - 1];
+        run_instruction(index + 1, line, x, |x| cycles.push(x))?;
+    }
+    Ok(cycles)
+}
+
+/**
+ * Run the instruction on line `number`, starting with register `x`, and pass `f` the register's
+ * value after each cycle it takes. Returns the register's new value.
+ */
+pub fn run_instruction(
+    number: usize,
+    line: &str,
+    x: i32,
+    mut f: impl FnMut(i32),
+) -> Result<i32, ParseError> {
+    let mut words = line.split_whitespace();
+    let command = words.next().unwrap_or(line);
 
-        match command {
-            "addx" => {
-                let value = words.next().unwrap().parse::<i32>().unwrap();
-                cycles.push(cycles[cycles.len() - 1]);
-                cycles.push(cycles[cycles.len() - 1] + value);
-            }
-            "noop" => {
-                cycles.push(cycles[cycles.len() - 1]);
-            }
-            _ => panic!("Unknown command: {}", command),
+    match command {
+        "addx" => {
+            let value = words.next().unwrap_or(&line[line.len()..]);
+            let value = value
+                .parse::<i32>()
+                .map_err(|_| ParseError::within(10, number, line, value, "a number"))?;
+            f(x);
+            let x = arith::add(10, x, value);
+            f(x);
+            Ok(x)
+        }
+        "noop" => {
+            f(x);
+            Ok(x)
         }
+        _ => Err(ParseError::within(
+            10,
+            number,
+            line,
+            command,
+            "addx or noop",
+        )),
     }
-    cycles
+}
+
+/**
+ * Stream the register values [`parse_cycles`] would return to `f`, running the program one line
+ * at a time.
+ */
+pub fn for_each_cycle(reader: impl BufRead, mut f: impl FnMut(i32)) -> Result<(), StreamError> {
+    let mut x = 1;
+    f(x);
+    for_each_line(reader, |number, line| {
+        x = run_instruction(number, line, x, &mut f)?;
+        Ok(())
+    })
 }
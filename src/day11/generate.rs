@@ -0,0 +1,37 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+const PRIMES: [usize; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/**
+ * Notes on `size` monkeys (between 2 and 12). Each tests a different prime, only the first squares
+ * its worry level, and none throws to itself.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, PRIMES.len());
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+    (0..count)
+        .map(|monkey| {
+            let items = (0..rng.below(6) + 1)
+                .map(|_| rng.range(40..=99).to_string())
+                .join(", ");
+            let operation = if monkey == 0 {
+                "old * old".to_string()
+            } else if rng.chance(0.5) {
+                format!("old + {}", rng.range(1..=8))
+            } else {
+                format!("old * {}", rng.range(2..=19))
+            };
+            let mut targets = (0..count).filter(|&m| m != monkey).collect::<Vec<_>>();
+            rng.shuffle(&mut targets);
+            let if_false = if targets.len() > 1 { targets[1] } else { targets[0] };
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    \
+                 If true: throw to monkey {}\n    If false: throw to monkey {}",
+                monkey, items, operation, primes[monkey], targets[0], if_false
+            )
+        })
+        .join("\n\n")
+}
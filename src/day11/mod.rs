@@ -0,0 +1,30 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+use shared::Monkey;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Monkey>, ParseError> {
+        shared::parse_monkeys(input)
+    }
+    fn part1(&self, monkeys: &Vec<Monkey>) -> usize {
+        part1::solve(monkeys)
+    }
+    fn part2(&self, monkeys: &Vec<Monkey>) -> usize {
+        part2::solve(monkeys)
+    }
+    fn check_record(&self, block: &str) -> Result<(), ParseError> {
+        shared::parse_monkeys_unchecked(block).map(|_| ())
+    }
+}
@@ -1,6 +1,8 @@
 use num_bigint::BigUint;
 
 use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
 
 fn run_round(mut monkeys: Vec<Monkey>) -> (Vec<Monkey>, Vec<usize>) {
     let len = monkeys.len();
@@ -14,13 +16,7 @@ fn run_round(mut monkeys: Vec<Monkey>) -> (Vec<Monkey>, Vec<usize>) {
             .iter()
             .map(|item| {
                 // inspect item, increase worry level
-                let new_item: BigUint = match monkey.operation {
-                    Operations::Add(value) => item + value,
-                    Operations::Subtract(value) => item - value,
-                    Operations::Multiply(value) => item * value,
-                    Operations::Divide(value) => item / value,
-                    Operations::Square() => item * item,
-                } / 3usize;
+                let new_item: BigUint = monkey.operation.apply(item) / 3usize;
 
                 // test worry level and throw to another monkey
                 let target = if new_item.clone() % monkey.test == BigUint::from(0usize) {
@@ -49,8 +45,8 @@ fn run_round(mut monkeys: Vec<Monkey>) -> (Vec<Monkey>, Vec<usize>) {
     (monkeys, inspections)
 }
 
-pub fn run(input: &str) -> usize {
-    let mut monkeys = parse_monkeys(input);
+pub fn solve(monkeys: &[Monkey]) -> usize {
+    let mut monkeys = monkeys.to_vec();
     let mut inspections: Vec<usize> = (0..monkeys.len()).map(|_| 0).collect();
 
     // println!("Starting monkeys: {:?}", monkeys);
@@ -65,23 +61,16 @@ pub fn run(input: &str) -> usize {
         inspections
             .iter_mut()
             .zip(new_inspections)
-            .for_each(|(i, j)| *i += j);
+            .for_each(|(i, j)| *i = arith::add(11, *i, j));
     }
     // for (i, inspection) in inspections.iter().enumerate() {
     //     println!("Monkey {} inspected items {} times", i, inspection);
     // }
     inspections.sort();
     inspections.reverse();
-    inspections[0] * inspections[1]
+    arith::mul(11, inspections[0], inspections[1])
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 10605usize);
-    }
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse_monkeys(input)?))
 }
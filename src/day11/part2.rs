@@ -1,6 +1,8 @@
 use num_bigint::BigUint;
 
 use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
 
 fn run_round(mut monkeys: Vec<Monkey>) -> (Vec<Monkey>, Vec<usize>) {
     let len = monkeys.len();
@@ -21,13 +23,7 @@ fn run_round(mut monkeys: Vec<Monkey>) -> (Vec<Monkey>, Vec<usize>) {
             .iter()
             .map(|item| {
                 // inspect item, increase worry level
-                let mut new_item: BigUint = match monkey.operation {
-                    Operations::Add(value) => item + value,
-                    Operations::Subtract(value) => item - value,
-                    Operations::Multiply(value) => item * value,
-                    Operations::Divide(value) => item / value,
-                    Operations::Square() => item * item,
-                };
+                let mut new_item = monkey.operation.apply(item);
 
                 // this keeps the worry level manageable without corrupting the divisibility test
                 new_item %= &max;
@@ -59,8 +55,8 @@ fn run_round(mut monkeys: Vec<Monkey>) -> (Vec<Monkey>, Vec<usize>) {
     (monkeys, inspections)
 }
 
-pub fn run(input: &str) -> usize {
-    let mut monkeys = parse_monkeys(input);
+pub fn solve(monkeys: &[Monkey]) -> usize {
+    let mut monkeys = monkeys.to_vec();
     let mut inspections: Vec<usize> = (0..monkeys.len()).map(|_| 0).collect();
 
     for _ in 0..10000 {
@@ -69,20 +65,13 @@ pub fn run(input: &str) -> usize {
         inspections
             .iter_mut()
             .zip(new_inspections)
-            .for_each(|(i, j)| *i += j);
+            .for_each(|(i, j)| *i = arith::add(11, *i, j));
     }
     inspections.sort();
     inspections.reverse();
-    inspections[0] * inspections[1]
+    arith::mul(11, inspections[0], inspections[1])
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 2713310158usize);
-    }
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse_monkeys(input)?))
 }
@@ -1,6 +1,7 @@
-use itertools::Itertools;
 use num_bigint::BigUint;
-use regex::Regex;
+
+use crate::arith;
+use crate::parse::{blocks, Field, ParseError, Pattern};
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum Operations {
@@ -11,6 +12,32 @@ pub enum Operations {
     Square(),
 }
 
+impl Operations {
+    /**
+     * The new worry level of an item after this operation. Worry levels can't overflow, but when
+     * checking is enabled, going below zero or dividing by zero is reported like an overflow.
+     */
+    pub fn apply(&self, item: &BigUint) -> BigUint {
+        match *self {
+            Operations::Add(value) => item + value,
+            Operations::Subtract(value) => {
+                if arith::ENABLED && *item < BigUint::from(value) {
+                    arith::overflow(11, item, '-', value);
+                }
+                item - value
+            }
+            Operations::Multiply(value) => item * value,
+            Operations::Divide(value) => {
+                if arith::ENABLED && value == 0 {
+                    arith::overflow(11, item, '/', value);
+                }
+                item / value
+            }
+            Operations::Square() => item * item,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Monkey {
     pub items: Vec<BigUint>,
@@ -20,101 +47,89 @@ pub struct Monkey {
     pub if_false: usize, // Index of a monkey
 }
 
-pub fn parse_monkeys(input: &str) -> Vec<Monkey> {
-    let mut monkeys: Vec<Monkey> = vec![];
-
-    for line in input.lines() {
-        // check if line matches regex /Monkey (\d+)/
-
-        if Regex::new(r"Monkey (\d+)").unwrap().is_match(line) {
-            monkeys.push(Monkey {
-                items: vec![],
-                operation: Operations::Add(0),
-                test: 0,
-                if_true: 0,
-                if_false: 0,
-            });
-            continue;
-        }
-        // get current monkey
-        let monkey = monkeys.last_mut().unwrap();
-
-        // check starting items
-        if let Some(starting_items) = Regex::new(r"  Starting items: (.*)")
-            .unwrap()
-            .captures(line)
-            .map(|c| {
-                c.iter()
-                    .nth(1)
-                    .unwrap()
-                    .unwrap()
-                    .as_str()
-                    .split(", ")
-                    .map(|i| i.parse::<BigUint>().unwrap())
-                    .collect::<Vec<BigUint>>()
-            })
-        {
-            // println!("Starting items: {:?}", starting_items);
-            if !starting_items.is_empty() {
-                monkey.items = starting_items;
-                continue;
+/**
+ * Read every monkey. Each one must throw to another monkey that exists, and have a divisor other
+ * than 0.
+ */
+pub fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let monkeys = parse_blocks(input)?;
+    if monkeys.is_empty() {
+        return Err(ParseError::end_of_input(11, 0, "a monkey"));
+    }
+    for (index, (_, targets)) in monkeys.iter().enumerate() {
+        for target in targets {
+            let number = target.integer::<usize>("a monkey number")?;
+            if number >= monkeys.len() || number == index {
+                return Err(target.error(format!(
+                    "the number of another monkey, below {}",
+                    monkeys.len()
+                )));
             }
         }
+    }
+    Ok(monkeys.into_iter().map(|(monkey, _)| monkey).collect())
+}
 
-        // check operation
-        if let Some(operation_string) = Regex::new(r"  Operation: new = (.*)")
-            .unwrap()
-            .captures(line)
-            .map(|c| c.iter().nth(1).unwrap().unwrap().as_str().to_string())
-        {
-            let (_, operator, operand) = operation_string.split_whitespace().next_tuple().unwrap();
-            // println!("Operation: {} {}", operator, operand);
-            let operation = match operator {
-                "+" => Operations::Add(operand.parse().unwrap()),
-                "-" => Operations::Subtract(operand.parse().unwrap()),
-                "*" => match operand {
-                    "old" => Operations::Square(),
-                    _ => Operations::Multiply(operand.parse().unwrap()),
-                },
-                "/" => Operations::Divide(operand.parse().unwrap()),
-                _ => panic!("Unknown operation: {}", operator),
-            };
-            monkey.operation = operation;
-            continue;
-        }
-
-        // check test
-        if let Some(divisible) = Regex::new(r"  Test: divisible by (\d+)")
-            .unwrap()
-            .captures(line)
-            .map(|c| c.iter().nth(1).unwrap().unwrap().as_str().to_string())
-        {
-            monkey.test = divisible.parse().unwrap();
-            continue;
-        }
+/**
+ * Like [`parse_monkeys`], but without checking which monkeys they throw to, so one monkey's block
+ * can be checked on its own.
+ */
+pub fn parse_monkeys_unchecked(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    Ok(parse_blocks(input)?
+        .into_iter()
+        .map(|(monkey, _)| monkey)
+        .collect())
+}
 
-        // check if_true
-        if let Some(target) = Regex::new(r"    If true: throw to monkey (\d+)")
-            .unwrap()
-            .captures(line)
-            .map(|c| c.iter().nth(1).unwrap().unwrap().as_str().to_string())
-        {
-            monkey.if_true = target.parse().unwrap();
-            continue;
-        }
+/**
+ * Each monkey, with the fields naming the monkeys it throws to.
+ */
+fn parse_blocks(input: &str) -> Result<Vec<(Monkey, [Field<'_>; 2])>, ParseError> {
+    let monkey = Pattern::new(11, "Monkey {monkey}:");
+    let starting_items = Pattern::new(11, "  Starting items: {items}");
+    let operation = Pattern::new(11, "  Operation: new = old {op} {value}");
+    let test = Pattern::new(11, "  Test: divisible by {divisor}");
+    let if_true = Pattern::new(11, "    If true: throw to monkey {monkey}");
+    let if_false = Pattern::new(11, "    If false: throw to monkey {monkey}");
 
-        // check if_false
-        if let Some(target) = Regex::new(r"    If false: throw to monkey (\d+)")
-            .unwrap()
-            .captures(line)
-            .map(|c| c.iter().nth(1).unwrap().unwrap().as_str().to_string())
-        {
-            monkey.if_false = target.parse().unwrap();
-            continue;
-        }
-    }
+    blocks(11, input)
+        .into_iter()
+        .map(|mut block| {
+            block.next::<1>(&monkey)?;
+            let [items] = block.next(&starting_items)?;
+            let [operator, operand] = block.next(&operation)?;
+            let number = |expected| operand.integer::<usize>(expected);
+            let operation = match operator.text() {
+                "+" => Operations::Add(number("a number")?),
+                "-" => Operations::Subtract(number("a number")?),
+                "*" if operand.text() == "old" => Operations::Square(),
+                "*" => Operations::Multiply(number("a number or `old`")?),
+                "/" => Operations::Divide(number("a number")?),
+                _ => return Err(operator.error("+, -, * or /")),
+            };
+            let [divisor] = block.next(&test)?;
+            let [true_target] = block.next(&if_true)?;
+            let [false_target] = block.next(&if_false)?;
+            block.finish()?;
 
-    monkeys
+            let test = divisor.integer("a divisor")?;
+            if test == 0 {
+                return Err(divisor.error("a divisor other than 0"));
+            }
+            let monkey = Monkey {
+                items: items
+                    .list(", ")
+                    .iter()
+                    .map(|item| item.integer("a worry level"))
+                    .collect::<Result<_, _>>()?,
+                operation,
+                test,
+                if_true: true_target.integer("a monkey number")?,
+                if_false: false_target.integer("a monkey number")?,
+            };
+            Ok((monkey, [true_target, false_target]))
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -124,7 +139,7 @@ mod tests {
     #[test]
     fn test_parse_monkeys() {
         let demo = include_str!("inputs/sample.txt");
-        let monkey = parse_monkeys(demo)[0].clone();
+        let monkey = parse_monkeys(demo).unwrap()[0].clone();
         assert_eq!(
             monkey.items,
             vec![BigUint::from(79usize), BigUint::from(98usize)]
@@ -134,4 +149,32 @@ mod tests {
         assert_eq!(monkey.if_true, 2);
         assert_eq!(monkey.if_false, 3);
     }
+
+    #[test]
+    fn test_bad_references() {
+        let demo = include_str!("inputs/sample.txt");
+        let error =
+            parse_monkeys(&demo.replacen("throw to monkey 2", "throw to monkey 7", 1)).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (5, 30, "7")
+        );
+        assert_eq!(error.expected, "the number of another monkey, below 4");
+
+        let error =
+            parse_monkeys(&demo.replacen("throw to monkey 2", "throw to monkey 0", 1)).unwrap_err();
+        assert_eq!(error.line, 5);
+
+        let error =
+            parse_monkeys(&demo.replacen("divisible by 23", "divisible by 0", 1)).unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (4, "a divisor other than 0")
+        );
+
+        let first = demo.split("\n\n").next().unwrap();
+        assert!(parse_monkeys(first).is_err());
+        assert!(parse_monkeys_unchecked(first).is_ok());
+        assert!(parse_monkeys("").is_err());
+    }
 }
@@ -0,0 +1,51 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * A heightmap `size` rows tall and `size * 4` columns wide (at least 26), rising from `a` on the
+ * left to `z` on the right. A winding trail from `S` to `E` only ever climbs one step at a time, so
+ * `E` can always be reached; everywhere else is roughened up.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let height = size.max(1);
+    let width = (size * 4).max(26);
+    let base = |x: usize| (x * 25 / (width - 1)) as u8;
+
+    let mut trail = vec![false; width * height];
+    let mut y = rng.below(height);
+    let start = y;
+    for x in 0..width {
+        trail[y * width + x] = true;
+        // wander up or down before the next step right
+        for _ in 0..rng.below(4) {
+            let next = if rng.chance(0.5) {
+                y.saturating_sub(1)
+            } else {
+                (y + 1).min(height - 1)
+            };
+            y = next;
+            trail[y * width + x] = true;
+        }
+    }
+    let end = y;
+
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| {
+                    if x == 0 && y == start {
+                        'S'
+                    } else if x == width - 1 && y == end {
+                        'E'
+                    } else if trail[y * width + x] {
+                        (b'a' + base(x)) as char
+                    } else {
+                        let height = base(x) as i64 + rng.range(-3..=1);
+                        (b'a' + height.clamp(0, 25) as u8) as char
+                    }
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
@@ -0,0 +1,41 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+use crate::grid::Position;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::trace::Tracer;
+
+use part1::Map;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = (Map, Position, Position);
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<(Map, Position, Position), ParseError> {
+        part1::parse_map(input)
+    }
+    fn part1(&self, (map, start, end): &(Map, Position, Position)) -> i32 {
+        part1::solve(map, *start, *end)
+    }
+    fn part2(&self, (map, _, end): &(Map, Position, Position)) -> i32 {
+        part2::solve(map, *end)
+    }
+}
+
+/**
+ * Show the shortest path: from `S` to `E` for part 1, and from the closest lowest square to `E`
+ * for part 2.
+ */
+pub fn explain(input: &str, part: u8, tracer: &mut dyn Tracer) -> Result<String, ParseError> {
+    let (map, start, end) = part1::parse_map(input)?;
+    let answer = match part {
+        1 => part1::solve_explained(&map, start, end, tracer),
+        _ => part2::solve_explained(&map, end, tracer),
+    };
+    Ok(answer.to_string())
+}
@@ -1,91 +1,95 @@
-pub fn parse_map(input: &str) -> (Vec<Vec<i32>>, (i32, i32), (i32, i32)) {
-    let mut map: Vec<Vec<i32>> = vec![];
-    let mut start = (0, 0);
-    let mut end = (0, 0);
+use crate::grid::{Grid, Position};
+use crate::parse::ParseError;
+use crate::search::{bfs, Graph};
+use crate::trace::{NoTracer, Step, Tracer};
 
-    for (y, line) in input.lines().enumerate() {
-        map.push(
-            line.chars()
-                .enumerate()
-                .map(|(x, c)| {
-                    let height = match c {
-                        'S' => {
-                            start = (x as i32, y as i32);
-                            'a'
-                        }
-                        'E' => {
-                            end = (x as i32, y as i32);
-                            'z'
-                        }
-                        c => c,
-                    };
-                    (height as i32) - ('a' as i32)
-                })
-                .collect(),
-        );
-    }
+pub type Map = Grid<i32>;
 
-    (map, start, end)
+pub fn parse_map(input: &str) -> Result<(Map, Position, Position), ParseError> {
+    let mut start = None;
+    let mut end = None;
+    let map = Grid::parse(input.lines(), |(x, y), c| {
+        let height = match c {
+            'S' => {
+                start = Some((x, y));
+                'a'
+            }
+            'E' => {
+                end = Some((x, y));
+                'z'
+            }
+            'a'..='z' => c,
+            _ => {
+                return Err(ParseError::new(
+                    12,
+                    y + 1,
+                    x + 1,
+                    c.to_string(),
+                    "a height from a to z, S or E",
+                ))
+            }
+        };
+        Ok((height as i32) - ('a' as i32))
+    })?;
+
+    let start =
+        start.ok_or_else(|| ParseError::end_of_input(12, map.height(), "a start position `S`"))?;
+    let end = end
+        .ok_or_else(|| ParseError::end_of_input(12, map.height(), "a best signal position `E`"))?;
+    Ok((map, start, end))
 }
 
 /**
- * Get the neighbors of a node on a map.
+ * The map as seen when climbing from `S` towards `E`: each step can only ascend by 1, but can
+ * descend by any amount.
  */
-fn get_neighbors(map: &Vec<Vec<i32>>, node: (i32, i32)) -> Vec<(i32, i32)> {
-    let mut neighbors = vec![
-        (node.0, node.1 - 1),
-        (node.0, node.1 + 1),
-        (node.0 - 1, node.1),
-        (node.0 + 1, node.1),
-    ];
-    neighbors.retain(|n| {
-        n.0 >= 0
-            && n.1 >= 0
-            && n.0 < map[0].len() as i32
-            && n.1 < map.len() as i32
-            && map[n.1 as usize][n.0 as usize] <= map[node.1 as usize][node.0 as usize] + 1
-    });
-    neighbors
+pub struct Ascent<'a>(pub &'a Map);
+
+impl Graph for Ascent<'_> {
+    type Node = Position;
+
+    fn neighbors(&self, node: &Position) -> impl Iterator<Item = (Position, usize)> {
+        let (map, node) = (self.0, *node);
+        map.neighbors4(node)
+            .filter(move |&n| map[n] <= map[node] + 1)
+            .map(|n| (n, 1))
+    }
 }
 
 /**
- * Find the shortest path between two points on a map. Each step can only ascend by 1, but can descend by any amount.
- *
- * Return the length of the shortest path.
+ * Find the length of the shortest path between two points on a map, or 0 if there isn't one.
  */
-fn breadth_first_search(map: Vec<Vec<i32>>, start: (i32, i32), end: (i32, i32)) -> i32 {
-    let mut queue = vec![(start, 0)];
-    let mut visited = vec![vec![false; map[0].len()]; map.len()];
+pub fn solve(map: &Map, start: Position, end: Position) -> i32 {
+    solve_explained(map, start, end, &mut NoTracer)
+}
 
-    while !queue.is_empty() {
-        let (node, distance) = queue.remove(0);
-        if node == end {
-            return distance;
-        }
-        if visited[node.1 as usize][node.0 as usize] {
-            continue;
-        }
-        visited[node.1 as usize][node.0 as usize] = true;
-        for neighbor in get_neighbors(&map, node) {
-            queue.push((neighbor, distance + 1));
-        }
+/**
+ * Like [`solve`], sending each square of the shortest path to `tracer`.
+ */
+pub fn solve_explained(map: &Map, start: Position, end: Position, tracer: &mut dyn Tracer) -> i32 {
+    let search = bfs(&Ascent(map), start, |node| *node == end);
+    if tracer.enabled() {
+        trace_path(map, &search.goal_path().unwrap_or_default(), tracer);
     }
-    0
+    search.goal_distance().map_or(0, |distance| distance as i32)
 }
 
-pub fn run(input: &str) -> i32 {
-    let (map, start, end) = parse_map(input);
-
-    breadth_first_search(map, start, end)
+/**
+ * Send each square of a path to `tracer`, numbered from 0 at its start, with its height.
+ */
+pub fn trace_path(map: &Map, path: &[Position], tracer: &mut dyn Tracer) {
+    for (index, &(x, y)) in path.iter().enumerate() {
+        tracer.step(
+            Step::new("square")
+                .with("step", index)
+                .with("x", x)
+                .with("y", y)
+                .with("height", ((b'a' + map[(x, y)] as u8) as char).to_string()),
+        );
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 31i32);
-    }
+pub fn run(input: &str) -> Result<i32, ParseError> {
+    let (map, start, end) = parse_map(input)?;
+    Ok(solve(&map, start, end))
 }
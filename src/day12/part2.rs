@@ -1,97 +1,50 @@
 // Just like part 1, but backwards: we set all the `a` squares as our goal, and find the shortest path from `E` to any of those squares.
 
-pub fn parse_map(input: &str) -> (Vec<Vec<i32>>, Vec<(i32, i32)>, (i32, i32)) {
-    let mut map: Vec<Vec<i32>> = vec![];
-    let mut start = vec![(0, 0)];
-    let mut end = (0, 0);
-
-    for (y, line) in input.lines().enumerate() {
-        map.push(
-            line.chars()
-                .enumerate()
-                .map(|(x, c)| {
-                    let height = match c {
-                        'S' => {
-                            start.push((x as i32, y as i32));
-                            'a'
-                        }
-                        'E' => {
-                            end = (x as i32, y as i32);
-                            'z'
-                        }
-                        'a' => {
-                            start.push((x as i32, y as i32));
-                            'a'
-                        }
-                        c => c,
-                    };
-                    (height as i32) - ('a' as i32)
-                })
-                .collect(),
-        );
-    }
-
-    (map, start, end)
-}
+use super::part1::*;
+use crate::grid::Position;
+use crate::parse::ParseError;
+use crate::search::{bfs, Graph};
+use crate::trace::{NoTracer, Tracer};
 
 /**
- * Get the neighbors of a node on a map.
+ * The map as seen when walking back down from `E`: each step can only descend by 1, but can
+ * ascend by any amount.
  */
-fn get_neighbors(map: &Vec<Vec<i32>>, node: (i32, i32)) -> Vec<(i32, i32)> {
-    let mut neighbors = vec![
-        (node.0, node.1 - 1),
-        (node.0, node.1 + 1),
-        (node.0 - 1, node.1),
-        (node.0 + 1, node.1),
-    ];
-    neighbors.retain(|n| {
-        n.0 >= 0
-            && n.1 >= 0
-            && n.0 < map[0].len() as i32
-            && n.1 < map.len() as i32
-            && map[n.1 as usize][n.0 as usize] >= map[node.1 as usize][node.0 as usize] - 1
-    });
-    neighbors
-}
+pub struct Descent<'a>(pub &'a Map);
 
-/**
- * Find the shortest path between two points on a map. Each step can only ascend by 1, but can descend by any amount.
- *
- * Return the length of the shortest path.
- */
-fn breadth_first_search(map: Vec<Vec<i32>>, start: (i32, i32), end: Vec<(i32, i32)>) -> i32 {
-    let mut queue = vec![(start, 0)];
-    let mut visited = vec![vec![false; map[0].len()]; map.len()];
+impl Graph for Descent<'_> {
+    type Node = Position;
 
-    while !queue.is_empty() {
-        let (node, distance) = queue.remove(0);
-        if end.contains(&node) {
-            return distance;
-        }
-        if visited[node.1 as usize][node.0 as usize] {
-            continue;
-        }
-        visited[node.1 as usize][node.0 as usize] = true;
-        for neighbor in get_neighbors(&map, node) {
-            queue.push((neighbor, distance + 1));
-        }
+    fn neighbors(&self, node: &Position) -> impl Iterator<Item = (Position, usize)> {
+        let (map, node) = (self.0, *node);
+        map.neighbors4(node)
+            .filter(move |&n| map[n] >= map[node] - 1)
+            .map(|n| (n, 1))
     }
-    i32::MAX
 }
 
-pub fn run(input: &str) -> i32 {
-    let (map, start, end) = parse_map(input);
-
-    breadth_first_search(map, end, start)
+pub fn solve(map: &Map, end: Position) -> i32 {
+    solve_explained(map, end, &mut NoTracer)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 29i32);
+/**
+ * Like [`solve`], sending each square of the shortest hike to `tracer`, from the lowest square up
+ * to `E`.
+ */
+pub fn solve_explained(map: &Map, end: Position, tracer: &mut dyn Tracer) -> i32 {
+    // every square at the lowest elevation (`S` or `a`) is a potential starting point
+    let search = bfs(&Descent(map), end, |node| map[*node] == 0);
+    if tracer.enabled() {
+        let mut path = search.goal_path().unwrap_or_default();
+        path.reverse();
+        trace_path(map, &path, tracer);
     }
+    search
+        .goal_distance()
+        .map_or(i32::MAX, |distance| distance as i32)
+}
+
+pub fn run(input: &str) -> Result<i32, ParseError> {
+    let (map, _, end) = parse_map(input)?;
+    Ok(solve(&map, end))
 }
@@ -0,0 +1,38 @@
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+use serde_json::Value;
+
+use super::part1::compare;
+use crate::random::Rng;
+
+/**
+ * `size` pairs of packets, nested up to four lists deep. Neither packet in a pair compares equal to
+ * the other, so every pair is either in the right order or not.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| loop {
+            let left = packet(rng, 0);
+            let right = packet(rng, 0);
+            if compare(&left, &right) != Ordering::Equal {
+                break format!("{}\n{}", left, right);
+            }
+        })
+        .join("\n\n")
+}
+
+fn packet(rng: &mut Rng, depth: usize) -> Value {
+    let length = rng.below(5);
+    Value::Array(
+        (0..length)
+            .map(|_| {
+                if depth < 3 && rng.chance(0.3) {
+                    packet(rng, depth + 1)
+                } else {
+                    Value::from(rng.range(0..=10))
+                }
+            })
+            .collect(),
+    )
+}
@@ -0,0 +1,35 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+use serde_json::Value;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::trace::Tracer;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<Value>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Vec<Value>, ParseError> {
+        part1::parse_packets(input)
+    }
+    fn part1(&self, packets: &Vec<Value>) -> i32 {
+        part1::solve(packets)
+    }
+    fn part2(&self, packets: &Vec<Value>) -> i32 {
+        part2::solve(packets)
+    }
+}
+
+/**
+ * Show which pairs of packets are in the right order. Only part 1 can be explained.
+ */
+pub fn explain(input: &str, _: u8, tracer: &mut dyn Tracer) -> Result<String, ParseError> {
+    let packets = part1::parse_packets(input)?;
+    Ok(part1::solve_explained(&packets, tracer).to_string())
+}
@@ -3,6 +3,10 @@ use std::cmp::Ordering;
 use itertools::{EitherOrBoth::*, Itertools};
 use serde_json::{json, Value};
 
+use crate::arith;
+use crate::parse::ParseError;
+use crate::trace::{NoTracer, Step, Tracer};
+
 pub fn compare(v1: &Value, v2: &Value) -> Ordering {
     if v1.is_array() && v2.is_array() {
         let v1 = v1.as_array().unwrap();
@@ -32,29 +36,60 @@ pub fn compare(v1: &Value, v2: &Value) -> Ordering {
     }
 }
 
-pub fn run(input: &str) -> i32 {
-    // parse lines as JSON using serde
-    let sets = input
+/**
+ * Check that a value only contains lists and integers, which is all a packet can hold
+ */
+fn is_packet_data(value: &Value) -> bool {
+    match value {
+        Value::Array(values) => values.iter().all(is_packet_data),
+        Value::Number(n) => n.is_u64(),
+        _ => false,
+    }
+}
+
+/**
+ * Parse each non-blank line as a JSON packet
+ */
+pub fn parse_packets(input: &str) -> Result<Vec<Value>, ParseError> {
+    let expected = "a packet like `[1,[2,3]]`";
+    input
         .lines()
-        .filter_map(|line| serde_json::from_str(line).ok())
-        .collect::<Vec<Value>>();
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| {
+            let packet = serde_json::from_str(line)
+                .map_err(|e| ParseError::new(13, index + 1, e.column(), line, expected))?;
+            match packet {
+                Value::Array(_) if is_packet_data(&packet) => Ok(packet),
+                _ => Err(ParseError::line(13, index + 1, line, expected)),
+            }
+        })
+        .collect::<Result<Vec<Value>, ParseError>>()
+}
+
+pub fn solve(sets: &[Value]) -> i32 {
+    solve_explained(sets, &mut NoTracer)
+}
 
+/**
+ * Like [`solve`], sending the index of each pair that's in the right order to `tracer`.
+ */
+pub fn solve_explained(sets: &[Value], tracer: &mut dyn Tracer) -> i32 {
     let mut sum_of_indices = 0;
     for (index, set) in sets.windows(2).step_by(2).enumerate() {
         if compare(&set[0], &set[1]) == Ordering::Less {
-            sum_of_indices += (index + 1) as i32;
+            sum_of_indices = arith::add(13, sum_of_indices, (index + 1) as i32);
+            tracer.emit(|| {
+                Step::new("pair in order")
+                    .with("index", index + 1)
+                    .with("left", set[0].to_string())
+                    .with("right", set[1].to_string())
+            });
         }
     }
     sum_of_indices
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 13i32);
-    }
+pub fn run(input: &str) -> Result<i32, ParseError> {
+    Ok(solve(&parse_packets(input)?))
 }
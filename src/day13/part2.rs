@@ -3,14 +3,15 @@ use serde_json::{json, Value};
 use std::cmp::Ordering;
 
 use super::part1::*;
+use crate::arith;
+use crate::parse::ParseError;
 
-pub fn run(input: &str) -> i32 {
+pub fn solve(packets: &[Value]) -> i32 {
     let divider1 = json![[[2]]];
     let divider2 = json![[[6]]];
-    // parse lines as JSON using serde
-    let sets = input
-        .lines()
-        .filter_map(|line| serde_json::from_str(line).ok())
+    let sets = packets
+        .iter()
+        .cloned()
         .chain([divider1.clone(), divider2.clone()])
         .sorted_by(compare)
         .collect::<Vec<Value>>();
@@ -24,16 +25,9 @@ pub fn run(input: &str) -> i32 {
         .find_position(|&x| compare(x, &divider2) == Ordering::Equal)
         .unwrap();
 
-    ((pos1 + 1) * (pos2 + 1)) as i32
+    arith::mul(13, pos1 + 1, pos2 + 1) as i32
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 140i32);
-    }
+pub fn run(input: &str) -> Result<i32, ParseError> {
+    Ok(solve(&parse_packets(input)?))
 }
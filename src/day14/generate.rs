@@ -0,0 +1,30 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * `size` rock paths of 2 to 5 points each, within 80 columns either side of the sand source and
+ * between 2 and 100 rows below it.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let mut x = rng.range(420..=580);
+            let mut y = rng.range(2..=100);
+            let mut points = vec![(x, y)];
+            for i in 0..rng.below(4) + 1 {
+                // alternate between horizontal and vertical segments
+                if i % 2 == 0 {
+                    x = (x + rng.range(-8..=8)).clamp(420, 580);
+                } else {
+                    y = (y + rng.range(-8..=8)).clamp(2, 100);
+                }
+                points.push((x, y));
+            }
+            points
+                .iter()
+                .map(|(x, y)| format!("{},{}", x, y))
+                .join(" -> ")
+        })
+        .join("\n")
+}
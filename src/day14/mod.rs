@@ -0,0 +1,38 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::visualize::Visualizer;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Grid<u8>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Grid<u8>, ParseError> {
+        part1::generate_map(input)
+    }
+    fn part1(&self, map: &Grid<u8>) -> i32 {
+        part1::solve(map)
+    }
+    fn part2(&self, map: &Grid<u8>) -> i32 {
+        part2::solve(map)
+    }
+}
+
+/**
+ * Watch the sand pile up, one frame per grain.
+ */
+pub fn visualize(input: &str, part: u8, visualizer: &mut dyn Visualizer) -> Result<(), ParseError> {
+    let map = part1::generate_map(input)?;
+    match part {
+        1 => part1::solve_with(&map, visualizer),
+        _ => part2::solve_with(&map, visualizer),
+    };
+    Ok(())
+}
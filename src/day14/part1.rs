@@ -1,28 +1,58 @@
 use itertools::Itertools;
 
-#[derive(Debug)]
-pub struct SandGrain {
-    pub x: usize,
-    pub y: usize,
-}
+use crate::geometry::Point2;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::visualize::{Frame, NoVisualizer, Visualizer};
 
-#[allow(clippy::needless_range_loop)]
-pub fn generate_map(input: &str) -> Vec<Vec<u8>> {
-    let walls = input.lines().map(|line| {
-        line.split(" -> ")
-            .map(|s| {
-                s.split(',')
-                    .map(|s| s.parse::<usize>().unwrap())
-                    .next_tuple::<(_, _)>()
-                    .unwrap()
-            })
-            .collect::<Vec<(usize, usize)>>()
-    });
+pub type SandGrain = Point2<usize>;
+
+pub fn generate_map(input: &str) -> Result<Grid<u8>, ParseError> {
+    let walls = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let points = line
+                .split(" -> ")
+                .map(|s| {
+                    let point = s.split(',').map(|n| n.parse::<usize>()).collect_tuple();
+                    match point {
+                        Some((Ok(x), Ok(y))) => Ok((x, y)),
+                        _ => Err(ParseError::within(
+                            14,
+                            index + 1,
+                            line,
+                            s,
+                            "a point like `498,4`",
+                        )),
+                    }
+                })
+                .collect::<Result<Vec<(usize, usize)>, ParseError>>()?;
+            // walls are made of horizontal and vertical segments only
+            if let Some(((x1, y1), (x2, y2))) = points
+                .iter()
+                .tuple_windows()
+                .find(|((x1, y1), (x2, y2))| x1 != x2 && y1 != y2)
+            {
+                let segment = format!("{},{} -> {},{}", x1, y1, x2, y2);
+                let column = line.find(&segment).map_or(1, |offset| offset + 1);
+                return Err(ParseError::new(
+                    14,
+                    index + 1,
+                    column,
+                    segment,
+                    "a horizontal or vertical wall",
+                ));
+            }
+            Ok(points)
+        })
+        .collect::<Result<Vec<Vec<(usize, usize)>>, ParseError>>()?;
 
     // get bounds of map
     let (max_x, max_y) = walls
-        .clone()
+        .iter()
         .flatten()
+        .copied()
         .fold((0, 0), |(max_x, max_y), (x, y)| {
             (
                 if x > max_x { x } else { max_x },
@@ -30,43 +60,55 @@ pub fn generate_map(input: &str) -> Vec<Vec<u8>> {
             )
         });
 
-    let mut map = vec![vec![0; max_x.max(500 * 2) + 1]; max_y + 1];
+    let mut map = Grid::new(max_x.max(500 * 2) + 1, max_y + 1, 0);
 
     // add walls to map
-    walls.for_each(|wall| {
+    walls.iter().for_each(|wall| {
         wall.iter()
             .tuple_windows()
             .for_each(|((x1, y1), (x2, y2))| {
                 if x1 == x2 {
                     for y in *y1.min(y2)..=*y1.max(y2) {
-                        map[y][*x1] = 1;
+                        map[(*x1, y)] = 1;
                     }
                 } else {
                     for x in *x1.min(x2)..=*x1.max(x2) {
-                        map[*y1][x] = 1;
+                        map[(x, *y1)] = 1;
                     }
                 }
             })
     });
 
-    map
+    Ok(map)
 }
 
-#[allow(dead_code)]
-fn render_map(map: &[Vec<u8>]) {
-    map.iter().for_each(|row| {
-        row.iter().for_each(|cell| {
-            print!(
-                "{}",
-                match cell {
+/**
+ * Render the map with `#` for walls and `o` for sand, cropped to the columns that have anything in
+ * them. Rows that are completely filled (like part 2's floor) don't count towards the crop.
+ */
+pub fn render_map(map: &Grid<u8>) -> String {
+    let used = map
+        .rows()
+        .filter(|row| row.contains(&0))
+        .flat_map(|row| row.iter().positions(|&cell| cell != 0))
+        .minmax()
+        .into_option();
+    let (left, right) = match used {
+        Some((left, right)) => (left.saturating_sub(1), (right + 1).min(map.width() - 1)),
+        None => (0, map.width() - 1),
+    };
+    map.rows()
+        .map(|row| {
+            row[left..=right]
+                .iter()
+                .map(|cell| match cell {
                     0 => '.',
                     1 => '#',
-                    _ => ' ',
-                }
-            );
-        });
-        println!();
-    });
+                    _ => 'o',
+                })
+                .collect::<String>()
+        })
+        .join("\n")
 }
 
 /**
@@ -76,62 +118,54 @@ fn render_map(map: &[Vec<u8>]) {
  *
  * Return the final position of the grain of sand.
  */
-pub fn simulate(map: &[Vec<u8>]) -> SandGrain {
-    let mut sand = SandGrain { x: 500, y: 0 };
+pub fn simulate(map: &Grid<u8>) -> SandGrain {
+    let mut sand = SandGrain::new(500, 0);
     loop {
         // if we're at the bottom of the map, stop
-        if sand.y == map.len() - 1 {
+        if sand.y == map.height() - 1 {
             break;
         }
 
-        // if we can move straight down, do so
-        if map[sand.y + 1][sand.x] == 0 {
-            sand.y += 1;
-            continue;
+        // move straight down if we can; otherwise, down and left; otherwise, down and right
+        let below = sand + SandGrain::new(0, 1);
+        let next = [
+            below,
+            below - SandGrain::new(1, 0),
+            below + SandGrain::new(1, 0),
+        ]
+        .into_iter()
+        .find(|next| map[(*next).into()] == 0);
+        match next {
+            Some(next) => sand = next,
+            // if we can't move anywhere, stop
+            None => break,
         }
-
-        // if we can move down and left, do so
-        if map[sand.y + 1][sand.x - 1] == 0 {
-            sand.y += 1;
-            sand.x -= 1;
-            continue;
-        }
-
-        // if we can move down and right, do so
-        if map[sand.y + 1][sand.x + 1] == 0 {
-            sand.y += 1;
-            sand.x += 1;
-            continue;
-        }
-
-        // if we can't move anywhere, stop
-        break;
     }
     sand
 }
 
-pub fn run(input: &str) -> i32 {
-    let mut map = generate_map(input);
-    // render_map(&map);
+pub fn solve(map: &Grid<u8>) -> i32 {
+    solve_with(map, &mut NoVisualizer)
+}
+
+/**
+ * Solve, sending a frame of the map each time a grain of sand comes to rest.
+ */
+pub fn solve_with(map: &Grid<u8>, visualizer: &mut dyn Visualizer) -> i32 {
+    let mut map = map.clone();
     let mut counter = 0;
     loop {
         let sand = simulate(&map);
-        if sand.y == map.len() - 1 {
+        if sand.y == map.height() - 1 {
             break;
         }
-        map[sand.y][sand.x] = 1;
+        map[sand.into()] = 2;
         counter += 1;
+        visualizer.emit(|| Frame::new(format!("Grain {}", counter), render_map(&map)));
     }
     counter
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 24i32);
-    }
+pub fn run(input: &str) -> Result<i32, ParseError> {
+    Ok(solve(&generate_map(input)?))
 }
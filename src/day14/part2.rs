@@ -1,16 +1,27 @@
 use super::part1::*;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::visualize::{Frame, NoVisualizer, Visualizer};
 
-pub fn run(input: &str) -> i32 {
-    let mut map = generate_map(input);
+pub fn solve(map: &Grid<u8>) -> i32 {
+    solve_with(map, &mut NoVisualizer)
+}
+
+/**
+ * Solve, sending a frame of the map each time a grain of sand comes to rest.
+ */
+pub fn solve_with(map: &Grid<u8>, visualizer: &mut dyn Visualizer) -> i32 {
+    let mut map = map.clone();
     // add floor
-    map.push(vec![0; map[0].len()]);
-    map.push(vec![1; map[0].len()]);
+    map.push_row(vec![0; map.width()]);
+    map.push_row(vec![1; map.width()]);
 
     let mut counter = 0;
     loop {
         let sand = simulate(&map);
-        map[sand.y][sand.x] = 1;
+        map[sand.into()] = 2;
         counter += 1;
+        visualizer.emit(|| Frame::new(format!("Grain {}", counter), render_map(&map)));
         if sand.y == 0 && sand.x == 500 {
             break;
         }
@@ -18,13 +29,6 @@ pub fn run(input: &str) -> i32 {
     counter
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 93i32);
-    }
+pub fn run(input: &str) -> Result<i32, ParseError> {
+    Ok(solve(&generate_map(input)?))
 }
@@ -0,0 +1,56 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * The search area for part 2, matching day 15's default `max_x` and `max_y` parameters.
+ */
+const MAX: i64 = 4_000_000;
+
+/**
+ * Readings from about `size` sensors (at least 5) that cover the whole search area except for one
+ * hidden position.
+ *
+ * Four sensors sit diagonally around the hidden position, just failing to reach it, and cover
+ * everything nearby. The rest are spread over a jittered grid, each with its beacon as far away as
+ * it can be without reaching the hidden position.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let across = ((size.max(5) - 4) as f64).sqrt().ceil() as i64;
+    let cell = MAX / across;
+    let hidden = (rng.range(0..=MAX), rng.range(0..=MAX));
+    let distance = |(x1, y1): (i64, i64), (x2, y2): (i64, i64)| (x1 - x2).abs() + (y1 - y2).abs();
+
+    let reach = cell * 3;
+    let mut sensors = [(-1, -1), (-1, 1), (1, -1), (1, 1)]
+        .iter()
+        .map(|(dx, dy)| (hidden.0 + dx * reach, hidden.1 + dy * reach))
+        .collect::<Vec<_>>();
+    for (i, j) in (0..across).cartesian_product(0..across) {
+        let jitter = cell / 8;
+        sensors.push((
+            i * cell + cell / 2 + rng.range(-jitter..=jitter),
+            j * cell + cell / 2 + rng.range(-jitter..=jitter),
+        ));
+    }
+
+    sensors
+        .into_iter()
+        .filter_map(|sensor| {
+            let radius = distance(sensor, hidden) - 1;
+            if radius < 1 {
+                return None;
+            }
+            // any point on the edge of the sensor's range
+            let dx = rng.range(-radius..=radius);
+            let dy = (radius - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+            Some(format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                sensor.0,
+                sensor.1,
+                sensor.0 + dx,
+                sensor.1 + dy
+            ))
+        })
+        .join("\n")
+}
@@ -0,0 +1,34 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+use part1::Point;
+
+/**
+ * Day 15 needs to know which row to scan for part 1, and the bounds of the search area for part 2.
+ * The sample uses much smaller values than the real input.
+ */
+pub struct Day15 {
+    pub row: i64,
+    pub max_x: i64,
+    pub max_y: i64,
+}
+
+impl Solution for Day15 {
+    type Parsed = Vec<(Point, Point)>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Vec<(Point, Point)>, ParseError> {
+        part1::parse_sensors(input)
+    }
+    fn part1(&self, sensors: &Vec<(Point, Point)>) -> i64 {
+        part1::solve(sensors, self.row)
+    }
+    fn part2(&self, sensors: &Vec<(Point, Point)>) -> i64 {
+        part2::solve(sensors, self.max_x, self.max_y)
+    }
+}
@@ -1,50 +1,47 @@
 use std::ops::Range;
 
 use itertools::Itertools;
-use regex::Regex;
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
-}
+use crate::arith;
+use crate::geometry::Point2;
+use crate::parse::{lines, Field, ParseError, Pattern};
 
-pub fn parse_sensors(input: &str) -> Vec<(Point, Point)> {
-    let mut points = vec![];
-    for line in input.lines() {
-        let re = Regex::new(r"Sensor at x=(?P<x1>\-?\d+), y=(?P<y1>\-?\d+): closest beacon is at x=(?P<x2>\-?\d+), y=(?P<y2>\-?\d+)").unwrap();
-        let caps = re.captures(line).unwrap();
-        points.push((
-            Point {
-                x: caps["x1"].parse().unwrap(),
-                y: caps["y1"].parse().unwrap(),
-            },
-            Point {
-                x: caps["x2"].parse().unwrap(),
-                y: caps["y2"].parse().unwrap(),
-            },
-        ))
-    }
-    points
+pub type Point = Point2<i32>;
+
+pub fn parse_sensors(input: &str) -> Result<Vec<(Point, Point)>, ParseError> {
+    let pattern = Pattern::new(15, "Sensor at {sensor}: closest beacon is at {beacon}");
+    let point = |field: Field| {
+        let [x, y] = field.split_n(", ", "`x=[x], y=[y]`")?;
+        Ok(Point {
+            x: x.key_value("x")?.signed("a coordinate")?,
+            y: y.key_value("y")?.signed("a coordinate")?,
+        })
+    };
+    lines(input)
+        .map(|(number, line)| {
+            let [sensor, beacon] = pattern.parse(number, line)?;
+            Ok((point(sensor)?, point(beacon)?))
+        })
+        .collect()
 }
 
 /**
- * Calculate Manhattan distance
+ * Each sensor with the Manhattan distance to its beacon. Coordinates are widened to `i64`, since
+ * distances between far-apart `i32` coordinates don't fit in an `i32`.
  */
-pub fn manhattan_distance(p1: &Point, p2: &Point) -> i32 {
-    (p1.x - p2.x).abs() + (p1.y - p2.y).abs()
-}
-
-pub fn run(input: &str, row: i32) -> i32 {
-    let sensors = parse_sensors(input);
-    let sensor_range = sensors
+pub fn sensor_ranges(sensors: &[(Point, Point)]) -> Vec<(Point2<i64>, i64)> {
+    let widen = |point: &Point| Point2::new(point.x as i64, point.y as i64);
+    sensors
         .iter()
-        .map(|(p1, p2)| (p1, manhattan_distance(p1, p2)))
-        .collect::<Vec<_>>();
+        .map(|(sensor, beacon)| (widen(sensor), widen(sensor).manhattan(widen(beacon))))
+        .collect()
+}
 
-    // values in range per sensor for a given row
-    let ranges = sensor_range
+pub fn solve(sensors: &[(Point, Point)], row: i64) -> i64 {
+    let ranges = sensor_ranges(sensors)
         .iter()
+        // sensors too far from the row don't cover any of it
+        .filter(|(p1, range)| (p1.y - row).abs() <= *range)
         .map(|(p1, range)| {
             let r1 = p1.x - (range - (p1.y - row).abs());
             let r2 = p1.x + (range - (p1.y - row).abs()) + 1;
@@ -55,38 +52,33 @@ pub fn run(input: &str, row: i32) -> i32 {
         })
         .sorted_by_key(|r| r.start)
         .collect::<Vec<_>>();
+    if ranges.is_empty() {
+        return 0;
+    }
 
-    // combine overlapping ranges
     let mut no_beacons = 0;
     let mut combined_range = ranges[0].clone();
     for range in ranges.iter().skip(1) {
         if range.start <= combined_range.end {
             combined_range.end = combined_range.end.max(range.end);
         } else {
-            no_beacons += combined_range.end - combined_range.start;
+            no_beacons = arith::add(15, no_beacons, combined_range.end - combined_range.start);
             combined_range = range.clone();
         }
     }
-    no_beacons += combined_range.count() as i32;
+    no_beacons = arith::add(15, no_beacons, combined_range.end - combined_range.start);
 
     // subtract spaces that actually have beacons
     no_beacons -= sensors
         .iter()
         .map(|(_, beacon)| (beacon.x, beacon.y))
-        .filter(|(_, y)| y == &row)
+        .filter(|&(_, y)| y as i64 == row)
         .unique()
-        .count() as i32;
+        .count() as i64;
 
     no_beacons
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo, 10), 26i32);
-    }
+pub fn run(input: &str, row: i64) -> Result<i64, ParseError> {
+    Ok(solve(&parse_sensors(input)?, row))
 }
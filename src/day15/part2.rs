@@ -3,8 +3,15 @@ use std::ops::Range;
 use itertools::Itertools;
 
 use super::part1::*;
-
-fn find_empty_space(sensor_range: &[(&Point, i32)], y: i32, max_x: i32) -> Option<Point> {
+use crate::arith;
+use crate::geometry::Point2;
+use crate::parse::ParseError;
+
+fn find_empty_space(
+    sensor_range: &[(Point2<i64>, i64)],
+    y: i64,
+    max_x: i64,
+) -> Option<Point2<i64>> {
     // values in range per sensor for a given row
     let ranges = sensor_range
         .iter()
@@ -27,10 +34,7 @@ fn find_empty_space(sensor_range: &[(&Point, i32)], y: i32, max_x: i32) -> Optio
             combined_range.end = combined_range.end.max(range.end);
         } else {
             if combined_range.end > 0 && combined_range.end <= max_x {
-                return Some(Point {
-                    x: combined_range.end,
-                    y,
-                });
+                return Some(Point2::new(combined_range.end, y));
             }
             combined_range = range.clone();
         }
@@ -38,29 +42,18 @@ fn find_empty_space(sensor_range: &[(&Point, i32)], y: i32, max_x: i32) -> Optio
     None
 }
 
-pub fn run(input: &str, max_x: i32, max_y: i32) -> i64 {
-    let sensors_beacons = parse_sensors(input);
-    let sensors = sensors_beacons
-        .iter()
-        .map(|(p1, p2)| (p1, manhattan_distance(p1, p2)))
-        .collect::<Vec<_>>();
+pub fn solve(sensors_beacons: &[(Point, Point)], max_x: i64, max_y: i64) -> i64 {
+    let sensors = sensor_ranges(sensors_beacons);
 
     for y in 0..max_y {
         if let Some(point) = find_empty_space(&sensors, y, max_x) {
-            return (point.x as i64) * 4000000 + (point.y as i64);
+            return arith::add(15, arith::mul(15, point.x, 4000000), point.y);
         }
     }
 
     0
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo, 20, 20), 56000011i64);
-    }
+pub fn run(input: &str, max_x: i64, max_y: i64) -> Result<i64, ParseError> {
+    Ok(solve(&parse_sensors(input)?, max_x, max_y))
 }
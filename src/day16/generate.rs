@@ -0,0 +1,56 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * A connected network of `size` valves (at least 2), starting from `AA`. About half have a flow
+ * rate, but never more than 15, since part 2 slows down quickly with more.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 26 * 26);
+    let mut names = (b'A'..=b'Z')
+        .cartesian_product(b'A'..=b'Z')
+        .map(|(a, b)| format!("{}{}", a as char, b as char))
+        .filter(|name| name != "AA")
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut names);
+    names.insert(0, "AA".to_string());
+    names.truncate(count);
+
+    let mut rates = vec![0; count];
+    let mut flowing = (1..count).collect::<Vec<_>>();
+    rng.shuffle(&mut flowing);
+    for &valve in flowing.iter().take((count / 2).min(15)) {
+        rates[valve] = rng.range(1..=25);
+    }
+
+    // a random spanning tree keeps everything reachable, plus a few shortcuts
+    let mut tunnels = vec![vec![]; count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for valve in 1..count {
+        connect(valve, rng.below(valve));
+    }
+    for _ in 0..count / 4 {
+        connect(rng.below(count), rng.below(count));
+    }
+
+    (0..count)
+        .map(|valve| {
+            let leads = tunnels[valve].iter().map(|&to| &names[to]).join(", ");
+            let tunnels = if tunnels[valve].len() == 1 {
+                format!("tunnel leads to valve {}", leads)
+            } else {
+                format!("tunnels lead to valves {}", leads)
+            };
+            format!(
+                "Valve {} has flow rate={}; {}",
+                names[valve], rates[valve], tunnels
+            )
+        })
+        .join("\n")
+}
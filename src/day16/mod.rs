@@ -0,0 +1,42 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+use std::collections::HashMap;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::trace::Tracer;
+
+use part1::Valve;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = HashMap<String, Valve>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<HashMap<String, Valve>, ParseError> {
+        part1::parse_valves(input)
+    }
+    fn part1(&self, valves: &HashMap<String, Valve>) -> i32 {
+        part1::solve(valves)
+    }
+    fn part2(&self, valves: &HashMap<String, Valve>) -> i32 {
+        part2::solve(valves)
+    }
+}
+
+/**
+ * Show the order the valves are opened in: by you alone for part 1, and by you and the elephant
+ * for part 2.
+ */
+pub fn explain(input: &str, part: u8, tracer: &mut dyn Tracer) -> Result<String, ParseError> {
+    let valves = part1::parse_valves(input)?;
+    let answer = match part {
+        1 => part1::solve_explained(&valves, tracer),
+        _ => part2::solve_explained(&valves, tracer),
+    };
+    Ok(answer.to_string())
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
-use regex::Regex;
+use crate::arith;
+use crate::parse::{lines, ParseError, Pattern};
+use crate::search::{dijkstra, Graph};
+use crate::trace::{NoTracer, Step, Tracer};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Valve {
@@ -17,24 +20,42 @@ pub struct Valve {
  *
  * Return a vec of Valve structs
  */
-pub fn parse_valves(input: &str) -> HashMap<String, Valve> {
+pub fn parse_valves(input: &str) -> Result<HashMap<String, Valve>, ParseError> {
+    let pattern = Pattern::new(16, "Valve {name} has flow rate={rate}; {tunnels}");
     let mut valves = HashMap::new();
-    for line in input.lines() {
-        let re = Regex::new(r"Valve (?P<name>\w+) has flow rate=(?P<flow_rate>\d+); tunnels? leads? to valves? (?P<tunnels>.+)").unwrap();
-        let caps = re.captures(line).unwrap();
-        let flow_rate = caps["flow_rate"].parse().unwrap();
+    let mut tunnels = vec![];
+    for (number, line) in lines(input) {
+        let [name, rate, leads_to] = pattern.parse(number, line)?;
+        let flow_rate = rate.integer("a flow rate")?;
+        let names = leads_to
+            .strip_prefix("tunnels lead to valves ")
+            .or_else(|| leads_to.strip_prefix("tunnel leads to valve "))
+            .ok_or_else(|| leads_to.error("`tunnels lead to valves [names]`"))?
+            .list(", ");
         let valve = Valve {
-            name: caps["name"].to_string(),
+            name: name.text().to_string(),
             flow_rate,
-            tunnels: caps["tunnels"]
-                .split(", ")
-                .map(|s| (s.to_string(), 1))
-                .collect(),
+            tunnels: names.iter().map(|s| (s.text().to_string(), 1)).collect(),
             open: flow_rate == 0, // treat valves with a flow rate of 0 as already open
         };
         valves.insert(valve.name.clone(), valve);
+        tunnels.extend(names);
     }
-    valves
+
+    // every tunnel has to lead to a valve that was described
+    if let Some(tunnel) = tunnels.iter().find(|s| !valves.contains_key(s.text())) {
+        return Err(tunnel.error("a known valve"));
+    }
+    // the search always starts at valve AA
+    if !valves.contains_key("AA") {
+        return Err(ParseError::end_of_input(
+            16,
+            input.lines().count(),
+            "a valve named AA",
+        ));
+    }
+
+    Ok(valves)
 }
 
 fn potential_flow_rate(valve: &Valve) -> i32 {
@@ -63,32 +84,29 @@ pub fn simplify_valve_map(
     simplified_map
 }
 
-pub fn valve_distances(valves: &HashMap<String, Valve>, start: String) -> Vec<(String, i32)> {
-    let mut frontier = vec![start.clone()];
-    let mut visited = vec![];
-    let mut distance = HashMap::new();
-    distance.insert(start.clone(), 0);
-
-    while !frontier.is_empty() {
-        let current = frontier.pop().unwrap();
-        visited.push(current.clone());
-        let current_valve = valves.get(&current).unwrap();
-        for (next, d) in &current_valve.tunnels {
-            if !visited.contains(next) {
-                frontier.insert(0, next.clone());
-                let next_distance = distance.get(&current).unwrap() + d;
-                if distance.contains_key(next) && distance.get(next).unwrap() < &next_distance {
-                    continue;
-                }
-                distance.insert(next.clone(), next_distance);
-            }
-        }
+/**
+ * The valves are a graph with each tunnel as an edge, weighted by the minutes it takes to walk.
+ */
+impl Graph for HashMap<String, Valve> {
+    type Node = String;
+
+    fn neighbors(&self, node: &String) -> impl Iterator<Item = (String, usize)> {
+        self[node]
+            .tunnels
+            .iter()
+            .map(|(next, d)| (next.clone(), *d as usize))
     }
+}
 
-    distance
-        .iter()
-        .map(|(k, v)| (k.clone(), v + 1))
-        .filter(|(k, _)| k != &start && valves.get(k).unwrap().flow_rate > 0)
+/**
+ * The time to walk from `start` to each other valve with a flow rate > 0 and open it.
+ */
+pub fn valve_distances(valves: &HashMap<String, Valve>, start: String) -> Vec<(String, i32)> {
+    dijkstra(valves, start.clone(), |_| false)
+        .distances
+        .into_iter()
+        .filter(|(k, _)| k != &start && valves[k].flow_rate > 0)
+        .map(|(k, v)| (k, v as i32 + 1))
         .collect()
 }
 
@@ -155,31 +173,67 @@ fn compute_score(valves: &HashMap<String, Valve>, path: &Vec<ValveScore>) -> i32
     let mut time_remaining = 30;
     for ValveScore(name, time) in path {
         time_remaining -= time;
-        score += time_remaining * potential_flow_rate(valves.get(name).unwrap());
+        let released = arith::mul(16, time_remaining, potential_flow_rate(&valves[name]));
+        score = arith::add(16, score, released);
     }
     score
 }
 
-pub fn run(input: &str) -> i32 {
-    let valves = parse_valves(input);
-    let simplified_valves = simplify_valve_map(&valves, "AA".to_string());
+pub fn solve(valves: &HashMap<String, Valve>) -> i32 {
+    solve_explained(valves, &mut NoTracer)
+}
+
+/**
+ * Like [`solve`], sending each valve of the best order to open them in to `tracer`.
+ */
+pub fn solve_explained(valves: &HashMap<String, Valve>, tracer: &mut dyn Tracer) -> i32 {
+    let simplified_valves = simplify_valve_map(valves, "AA".to_string());
     let results = walk_scores(&simplified_valves, "AA".to_string(), &30);
     let results = results
         .iter()
         .map(|path| (compute_score(&simplified_valves, path), path));
 
-    let (score, _) = results.max_by_key(|(score, _)| *score).unwrap();
-
+    let (score, path) = results.max_by_key(|(score, _)| *score).unwrap();
+
+    if tracer.enabled() {
+        let mut time_remaining = 30;
+        for ValveScore(name, time) in path {
+            time_remaining -= time;
+            trace_opened(
+                tracer,
+                None,
+                &valves[name],
+                30 - time_remaining,
+                time_remaining,
+            );
+        }
+    }
     score
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 1651i32);
+/**
+ * Send an opened valve to `tracer`, with who opened it (for part 2), when, and how much pressure
+ * it releases in the time left.
+ */
+pub fn trace_opened(
+    tracer: &mut dyn Tracer,
+    opener: Option<&str>,
+    valve: &Valve,
+    minute: i32,
+    time_remaining: i32,
+) {
+    let mut step = Step::new("opened valve");
+    if let Some(opener) = opener {
+        step = step.with("by", opener);
     }
+    tracer.step(
+        step.with("valve", valve.name.as_str())
+            .with("minute", minute)
+            .with("flow_rate", valve.flow_rate)
+            .with("released", arith::mul(16, time_remaining, valve.flow_rate)),
+    );
+}
+
+pub fn run(input: &str) -> Result<i32, ParseError> {
+    Ok(solve(&parse_valves(input)?))
 }
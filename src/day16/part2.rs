@@ -2,6 +2,9 @@ use itertools::Itertools;
 use std::collections::HashMap;
 
 use super::part1::*;
+use crate::arith;
+use crate::parse::ParseError;
+use crate::trace::{NoTracer, Tracer};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ValveScore {
@@ -83,28 +86,40 @@ fn walk_scores(
 fn compute_score(valves: &HashMap<String, Valve>, path: &Vec<ValveScore>) -> i32 {
     let mut score = 0;
     for valve in path {
-        score += valve.time_remaining * valves.get(&valve.valve).unwrap().flow_rate;
+        let released = arith::mul(16, valve.time_remaining, valves[&valve.valve].flow_rate);
+        score = arith::add(16, score, released);
     }
     score
 }
 
-fn best_score(valves: &HashMap<String, Valve>, paths: &[Vec<ValveScore>]) -> i32 {
+/**
+ * The best score of any of `paths`, and the path that gets it (empty if there are no paths).
+ */
+fn best_path(
+    valves: &HashMap<String, Valve>,
+    paths: Vec<Vec<ValveScore>>,
+) -> (i32, Vec<ValveScore>) {
     paths
-        .iter()
-        .map(|path| compute_score(valves, path))
-        .max()
-        .unwrap_or(0)
+        .into_iter()
+        .map(|path| (compute_score(valves, &path), path))
+        .max_by_key(|(score, _)| *score)
+        .unwrap_or((0, vec![]))
 }
 
 /**
  * Return all possible pairs of disjoint sets of valves. A pair of disjoint sets together
- * has all valves, but neither set overlaps.
+ * has all valves, but neither set overlaps. Each split is listed once, with the smaller set first.
  */
 fn disjoint_sets(valves: &[String]) -> Vec<(Vec<&String>, Vec<&String>)> {
     let mut sets = vec![];
 
-    for i in 0..(valves.len() / 2) {
+    for i in 0..=(valves.len() / 2) {
         for subset in valves.iter().combinations(i) {
+            // an even split would otherwise turn up twice, as (A, B) and (B, A)
+            if 2 * i == valves.len() && valves.first().is_some_and(|first| !subset.contains(&first))
+            {
+                continue;
+            }
             let complement: Vec<&String> =
                 valves.iter().filter(|v| !subset.contains(v)).collect_vec();
             sets.push((subset, complement));
@@ -114,41 +129,82 @@ fn disjoint_sets(valves: &[String]) -> Vec<(Vec<&String>, Vec<&String>)> {
     sets
 }
 
-pub fn run(input: &str) -> i32 {
-    let valves = parse_valves(input);
-    let simplified_valves = simplify_valve_map(&valves, "AA".to_string());
+pub fn solve(valves: &HashMap<String, Valve>) -> i32 {
+    solve_explained(valves, &mut NoTracer)
+}
+
+/**
+ * Like [`solve`], sending the valves you and the elephant open, in the best split between you, to
+ * `tracer`.
+ */
+pub fn solve_explained(valves: &HashMap<String, Valve>, tracer: &mut dyn Tracer) -> i32 {
+    let simplified_valves = simplify_valve_map(valves, "AA".to_string());
 
     let keys = &simplified_valves.keys().cloned().collect_vec();
     let sets = disjoint_sets(keys);
 
-    println!("sets: {:?}", sets.len());
-
-    let results = sets
+    let (score, my_path, elephant_path) = sets
         .iter()
         .map(|(me, elephant)| {
-            println!("me: {:?}, elephant: {:?}", me, elephant);
-            let my_score = best_score(
-                &valves,
-                &walk_scores(&simplified_valves, "AA".to_string(), elephant, &26),
+            let (my_score, my_path) = best_path(
+                valves,
+                walk_scores(&simplified_valves, "AA".to_string(), elephant, &26),
             );
-            let elephant_score = best_score(
-                &valves,
-                &walk_scores(&simplified_valves, "AA".to_string(), me, &26),
+            let (elephant_score, elephant_path) = best_path(
+                valves,
+                walk_scores(&simplified_valves, "AA".to_string(), me, &26),
             );
-            my_score + elephant_score
+            (my_score + elephant_score, my_path, elephant_path)
         })
-        .collect_vec();
+        .max_by_key(|(score, _, _)| *score)
+        .unwrap();
+
+    if tracer.enabled() {
+        for (opener, path) in [("you", my_path), ("elephant", elephant_path)] {
+            for valve in path {
+                let minute = 26 - valve.time_remaining;
+                trace_opened(
+                    tracer,
+                    Some(opener),
+                    &valves[&valve.valve],
+                    minute,
+                    valve.time_remaining,
+                );
+            }
+        }
+    }
+    score
+}
 
-    *results.iter().max().unwrap()
+pub fn run(input: &str) -> Result<i32, ParseError> {
+    Ok(solve(&parse_valves(input)?))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
 
     #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 1707i32);
+    fn test_disjoint_sets() {
+        // the even split has to be included, or part 2 misses the best way to share the valves
+        let valves = ["AA", "BB", "CC", "DD"].map(String::from);
+        let sets = disjoint_sets(&valves);
+        // no valves, one, or two of them (each even split only once)
+        assert_eq!(sets.len(), 1 + 4 + 3);
+        assert!(sets
+            .iter()
+            .any(|(me, elephant)| me.len() == 2 && elephant.len() == 2));
+        let splits = sets
+            .iter()
+            .map(|(me, elephant)| {
+                let mut split = [me.clone(), elephant.clone()];
+                split.sort();
+                split
+            })
+            .collect::<HashSet<_>>();
+        assert_eq!(splits.len(), sets.len());
+
+        assert_eq!(disjoint_sets(&[]).len(), 1);
     }
 }
@@ -0,0 +1,10 @@
+use crate::random::Rng;
+
+/**
+ * A pattern of `size` jets of gas.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| if rng.chance(0.5) { '<' } else { '>' })
+        .collect()
+}
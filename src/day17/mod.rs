@@ -0,0 +1,37 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::visualize::Visualizer;
+
+use shared::Jet;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed = Vec<Jet>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Jet>, ParseError> {
+        shared::parse_jets(input)
+    }
+    fn part1(&self, jets: &Vec<Jet>) -> i64 {
+        part1::solve(jets)
+    }
+    fn part2(&self, jets: &Vec<Jet>) -> i64 {
+        part2::solve(jets)
+    }
+}
+
+/**
+ * Watch the first 2022 rocks fall. Part 2 only runs the same simulation for longer, so it isn't
+ * visualized separately.
+ */
+pub fn visualize(input: &str, _: u8, visualizer: &mut dyn Visualizer) -> Result<(), ParseError> {
+    part1::solve_with(&shared::parse_jets(input)?, visualizer);
+    Ok(())
+}
@@ -1,31 +1,24 @@
-use itertools::Itertools;
-
 use super::shared::*;
+use crate::parse::ParseError;
+use crate::visualize::{NoVisualizer, Visualizer};
+
+pub fn solve(jets: &[Jet]) -> i64 {
+    solve_with(jets, &mut NoVisualizer)
+}
 
-pub fn run(input: &str) -> i64 {
+/**
+ * Solve, sending a frame of the top of the tower after every jet of gas.
+ */
+pub fn solve_with(jets: &[Jet], visualizer: &mut dyn Visualizer) -> i64 {
     let mut state = GameState::new();
-    let mut jets = input
-        .chars()
-        .map(|c| match c {
-            '<' => Jet::Left,
-            '>' => Jet::Right,
-            _ => panic!("invalid input"),
-        })
-        .collect_vec();
+    let mut jets = jets.to_vec();
     for _ in 0..2022 {
-        step_game_state_until_rock_lands(&mut state, &mut jets);
+        step_game_state_until_rock_lands(&mut state, &mut jets, visualizer);
     }
 
     state.tower_size()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 3068i64);
-    }
+pub fn run(input: &str) -> Result<i64, ParseError> {
+    Ok(solve(&parse_jets(input)?))
 }
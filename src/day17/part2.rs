@@ -1,23 +1,17 @@
-use itertools::Itertools;
-
 use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
+use crate::visualize::NoVisualizer;
 
-pub fn run(input: &str) -> i64 {
+pub fn solve(jets: &[Jet]) -> i64 {
     let mut state = GameState::new();
-    let mut jets = input
-        .chars()
-        .map(|c| match c {
-            '<' => Jet::Left,
-            '>' => Jet::Right,
-            _ => panic!("invalid input"),
-        })
-        .collect_vec();
+    let mut jets = jets.to_vec();
 
     let total_iterations: i64 = 1000000000000;
     let stable_interval = jets.len() as i64;
     // First stable_interval rocks gets the tower to a stable state
     for _ in 0..stable_interval {
-        step_game_state_until_rock_lands(&mut state, &mut jets);
+        step_game_state_until_rock_lands(&mut state, &mut jets, &mut NoVisualizer);
     }
     let base_height = state.tower_size();
     // now evaluate the repeating pattern
@@ -29,7 +23,7 @@ pub fn run(input: &str) -> i64 {
     for i in 1..10000 {
         // one iteration to calculate target value
         for _ in 0..stable_interval {
-            step_game_state_until_rock_lands(&mut state, &mut jets);
+            step_game_state_until_rock_lands(&mut state, &mut jets, &mut NoVisualizer);
         }
         let net_height = state.tower_size() - last_height;
         last_height = state.tower_size();
@@ -55,27 +49,24 @@ pub fn run(input: &str) -> i64 {
     }
     let repeating_height = (state.tower_size() - base_height) / 2;
     // compute the number of segments we can skip
-    let skip_segments =
-        (total_iterations - stable_interval) / (repeating_interval * stable_interval);
-    let remaining_rocks =
-        (total_iterations - stable_interval) % (repeating_interval * stable_interval);
+    let segment = arith::mul(17, repeating_interval, stable_interval);
+    let skip_segments = arith::div(17, total_iterations - stable_interval, segment);
+    let remaining_rocks = (total_iterations - stable_interval) % segment;
 
     // calculate the height of the remaining rocks
     for _ in 0..remaining_rocks {
-        step_game_state_until_rock_lands(&mut state, &mut jets);
+        step_game_state_until_rock_lands(&mut state, &mut jets, &mut NoVisualizer);
     }
     let remaining_height = state.tower_size() - base_height - (repeating_height * 2);
 
-    base_height + repeating_height * skip_segments + remaining_height
+    let skipped_height = arith::mul(17, repeating_height, skip_segments);
+    arith::add(
+        17,
+        arith::add(17, base_height, skipped_height),
+        remaining_height,
+    )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 1514285714288i64);
-    }
+pub fn run(input: &str) -> Result<i64, ParseError> {
+    Ok(solve(&parse_jets(input)?))
 }
@@ -1,3 +1,6 @@
+use crate::parse::ParseError;
+use crate::visualize::{Frame, Visualizer};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RockKind {
     Horizontal,
@@ -53,6 +56,8 @@ pub struct GameState {
     rock: Rock,
     floor: usize,
     height: usize,
+    /// How many rocks have landed so far
+    landed: usize,
 }
 impl GameState {
     pub fn new() -> GameState {
@@ -65,6 +70,7 @@ impl GameState {
             },
             floor: 0,
             height: 0,
+            landed: 0,
         }
     }
     pub fn tower_size(&self) -> i64 {
@@ -101,6 +107,7 @@ impl GameState {
             .0
             - 1;
         self.column = new_column[0..=self.floor].to_vec();
+        self.landed += 1;
         let next_kind = self.rock.next_kind();
         self.rock = Rock {
             x: 2,
@@ -109,47 +116,48 @@ impl GameState {
         };
     }
 }
+impl Default for GameState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 /**
- * Render the game state.
+ * Render the top `rows` rows of the game state, down to the floor at most.
  * The column is seven characters wide. Ignoring the most significant bit of the row, print "#" for
- * 1 and " " for 0.
+ * 1 and "." for 0.
  * The rock is a 4x4 tile represented by 16 bits. Its x position is relative to the left side of the column,
  * and the y position is relative to the bottom of the column.
+ * Rows are labelled with their height in the whole tower, not just the part of it still in the column.
  */
-#[allow(dead_code)]
-pub fn render_game_state(state: &GameState) {
+pub fn render_game_state(state: &GameState, rows: usize) -> String {
     let max_height = (state.column.len() as i64).max(state.rock.y + 4);
-    for total_y in 0..max_height {
+    let mut picture = String::new();
+    for total_y in 0..max_height.min(rows as i64) {
         let y = max_height - total_y - 1;
         let row = state.column.get(y as usize).unwrap_or(&0);
-        print!("{:02} ", y);
+        picture += &format!("{:>5} ", y as usize + state.height);
         for x in 0..7 {
-            print!(
-                "{}",
+            picture.push(
                 if x >= state.rock.x
                     && x < state.rock.x + 4
                     && y >= state.rock.y
                     && y < state.rock.y + 4
-                {
-                    if state.rock.rock()
+                    && state.rock.rock()
                         & (1 << ((3 - (y - state.rock.y)) * 4 + (x - state.rock.x)))
                         != 0
-                    {
-                        "@"
-                    } else {
-                        " "
-                    }
+                {
+                    '@'
                 } else if row & (1 << x) != 0 {
-                    "#"
+                    '#'
                 } else {
-                    "."
-                }
+                    '.'
+                },
             );
         }
-        println!();
+        picture.push('\n');
     }
-    println!("   -------\n")
+    picture + "      -------"
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -197,17 +205,38 @@ pub fn step_game_state(state: &mut GameState, jet: &Jet) {
     }
 }
 
+/**
+ * Step the game until the current rock lands, sending a frame after each step.
+ */
 pub fn step_game_state_until_rock_lands<'a>(
     state: &'a mut GameState,
     jets: &mut [Jet],
+    visualizer: &mut dyn Visualizer,
 ) -> &'a mut GameState {
     loop {
         let old_rock = state.rock.kind.clone();
         step_game_state(state, &jets[0]);
         jets.rotate_left(1);
+        visualizer.emit(|| {
+            Frame::new(
+                format!("Rock {}, height {}", state.landed + 1, state.tower_size()),
+                render_game_state(state, 40),
+            )
+        });
         if old_rock != state.rock.kind {
             break;
         }
     }
     state
 }
+
+pub fn parse_jets(input: &str) -> Result<Vec<Jet>, ParseError> {
+    input
+        .char_indices()
+        .map(|(offset, c)| match c {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            _ => Err(ParseError::char_at(17, 1, input, offset, "< or >")),
+        })
+        .collect()
+}
@@ -0,0 +1,37 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::geometry::Point3;
+use crate::random::Rng;
+
+/**
+ * A droplet of `size` cubes, grown one cube at a time onto a face of the cubes already there so
+ * it's all one piece. Growing by piling onto random cubes leaves some air pockets inside.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut cubes = vec![Point3::new(0i64, 0, 0)];
+    let mut seen = cubes.iter().copied().collect::<HashSet<_>>();
+    let faces = Point3::new(0, 0, 0).neighbors6().collect_vec();
+    while cubes.len() < size.max(1) {
+        let next = *rng.pick(&cubes) + *rng.pick(&faces);
+        if seen.insert(next) {
+            cubes.push(next);
+        }
+    }
+
+    // shift everything to start at 0, like the puzzle's droplets
+    let min = Point3::new(
+        cubes.iter().map(|cube| cube.x).min().unwrap(),
+        cubes.iter().map(|cube| cube.y).min().unwrap(),
+        cubes.iter().map(|cube| cube.z).min().unwrap(),
+    );
+    rng.shuffle(&mut cubes);
+    cubes
+        .iter()
+        .map(|&cube| {
+            let cube = cube - min;
+            format!("{},{},{}", cube.x, cube.y, cube.z)
+        })
+        .join("\n")
+}
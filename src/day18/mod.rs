@@ -0,0 +1,29 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use std::collections::HashSet;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+use shared::Point3D;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = HashSet<Point3D>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<HashSet<Point3D>, ParseError> {
+        shared::parse_points(input)
+    }
+    fn part1(&self, points: &HashSet<Point3D>) -> i32 {
+        part1::solve(points)
+    }
+    fn part2(&self, points: &HashSet<Point3D>) -> i32 {
+        part2::solve(points)
+    }
+}
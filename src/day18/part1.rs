@@ -1,31 +1,20 @@
 use std::collections::HashSet;
 
 use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
 
-fn surface_area(points: HashSet<Point3D>) -> usize {
-    let mut surface_area = 0;
-    for point in points.iter() {
-        surface_area += point
-            .manhattan_neighbors()
-            .iter()
-            .filter(|p| !points.contains(p))
-            .count();
-    }
-    surface_area
+fn surface_area(points: &HashSet<Point3D>) -> usize {
+    let exposed = points
+        .iter()
+        .map(|point| point.neighbors6().filter(|p| !points.contains(p)).count());
+    arith::sum(18, exposed)
 }
 
-pub fn run(input: &str) -> i32 {
-    let points = parse_points(input);
+pub fn solve(points: &HashSet<Point3D>) -> i32 {
     surface_area(points) as i32
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 64i32);
-    }
+pub fn run(input: &str) -> Result<i32, ParseError> {
+    Ok(solve(&parse_points(input)?))
 }
@@ -2,28 +2,23 @@ use std::collections::HashSet;
 
 use itertools::Itertools;
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash)]
-pub struct Point3D(pub i32, pub i32, pub i32);
+use crate::geometry::Point3;
+use crate::parse::ParseError;
 
-impl Point3D {
-    pub fn manhattan_neighbors(&self) -> Vec<Point3D> {
-        vec![
-            Point3D(self.0 + 1, self.1, self.2),
-            Point3D(self.0 - 1, self.1, self.2),
-            Point3D(self.0, self.1 + 1, self.2),
-            Point3D(self.0, self.1 - 1, self.2),
-            Point3D(self.0, self.1, self.2 + 1),
-            Point3D(self.0, self.1, self.2 - 1),
-        ]
-    }
-}
+pub type Point3D = Point3<i32>;
 
-pub fn parse_points(input: &str) -> HashSet<Point3D> {
+pub fn parse_points(input: &str) -> Result<HashSet<Point3D>, ParseError> {
     let mut points = HashSet::new();
-    for line in input.lines() {
-        let mut parts = line.split(',').map(|s| s.parse::<i32>().unwrap());
-        let (x, y, z) = parts.next_tuple().unwrap();
-        points.insert(Point3D(x, y, z));
+    for (index, line) in input.lines().enumerate() {
+        let (x, y, z) = line
+            .split(',')
+            .map(|s| {
+                s.parse::<i32>()
+                    .map_err(|_| ParseError::within(18, index + 1, line, s, "a coordinate"))
+            })
+            .collect_tuple()
+            .ok_or_else(|| ParseError::line(18, index + 1, line, "a point like `2,2,2`"))?;
+        points.insert(Point3D::new(x?, y?, z?));
     }
-    points
+    Ok(points)
 }
@@ -0,0 +1,25 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * `size` blueprints with robot costs in the same ranges as the puzzle's.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (1..=size.max(1))
+        .map(|id| {
+            format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                 Each obsidian robot costs {} ore and {} clay. \
+                 Each geode robot costs {} ore and {} obsidian.",
+                id,
+                rng.range(2..=4),
+                rng.range(2..=4),
+                rng.range(2..=4),
+                rng.range(5..=20),
+                rng.range(2..=4),
+                rng.range(5..=20)
+            )
+        })
+        .join("\n")
+}
@@ -0,0 +1,40 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::trace::Tracer;
+
+use shared::Blueprint;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = Vec<Blueprint>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Blueprint>, ParseError> {
+        shared::parse_blueprints(input)
+    }
+    fn part1(&self, blueprints: &Vec<Blueprint>) -> i64 {
+        part1::solve(blueprints)
+    }
+    fn part2(&self, blueprints: &Vec<Blueprint>) -> i64 {
+        part2::solve(blueprints)
+    }
+}
+
+/**
+ * Show the robots each blueprint builds, and the geodes it opens.
+ */
+pub fn explain(input: &str, part: u8, tracer: &mut dyn Tracer) -> Result<String, ParseError> {
+    let blueprints = shared::parse_blueprints(input)?;
+    let answer = match part {
+        1 => part1::solve_explained(&blueprints, tracer),
+        _ => part2::solve_explained(&blueprints, tracer),
+    };
+    Ok(answer.to_string())
+}
@@ -1,4 +1,7 @@
 use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
+use crate::trace::{NoTracer, Step, Tracer};
 
 /**
  * For each possible option, recursively build the tree of possible states,
@@ -27,9 +30,15 @@ pub fn walk_tree(state: State) -> Option<Vec<State>> {
     best_state
 }
 
-pub fn run(input: &str) -> i32 {
-    let blueprints = parse_blueprints(input);
+pub fn solve(blueprints: &[Blueprint]) -> i64 {
+    solve_explained(blueprints, &mut NoTracer)
+}
 
+/**
+ * Like [`solve`], sending the robots each blueprint builds and the quality level it reaches to
+ * `tracer`.
+ */
+pub fn solve_explained(blueprints: &[Blueprint], tracer: &mut dyn Tracer) -> i64 {
     let mut quality_levels = 0;
 
     for blueprint in blueprints.iter() {
@@ -52,20 +61,21 @@ pub fn run(input: &str) -> i32 {
 
         let best_state = walk_tree(state);
         if let Some(states) = best_state {
-            quality_levels += states[0].inventory.geode * blueprint.id;
+            let quality = arith::mul(19, states[0].inventory.geode, blueprint.id);
+            trace_builds(&states, 24, tracer);
+            tracer.emit(|| {
+                Step::new("blueprint")
+                    .with("id", blueprint.id)
+                    .with("geodes", states[0].inventory.geode)
+                    .with("quality", quality)
+            });
+            quality_levels = arith::add(19, quality_levels, quality);
         }
     }
 
     quality_levels
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 33i32);
-    }
+pub fn run(input: &str) -> Result<i64, ParseError> {
+    Ok(solve(&parse_blueprints(input)?))
 }
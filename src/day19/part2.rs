@@ -1,4 +1,7 @@
 use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
+use crate::trace::{NoTracer, Step, Tracer};
 
 /**
  * For each possible option, recursively build the tree of possible states,
@@ -28,9 +31,15 @@ pub fn walk_tree(state: State) -> Option<Vec<State>> {
     best_state
 }
 
-pub fn run(input: &str) -> i32 {
-    let blueprints = parse_blueprints(input);
+pub fn solve(blueprints: &[Blueprint]) -> i64 {
+    solve_explained(blueprints, &mut NoTracer)
+}
 
+/**
+ * Like [`solve`], sending the robots each of the first three blueprints builds and the geodes it
+ * opens to `tracer`.
+ */
+pub fn solve_explained(blueprints: &[Blueprint], tracer: &mut dyn Tracer) -> i64 {
     let mut geode_counts = 1;
 
     for blueprint in blueprints.iter().take(3) {
@@ -53,21 +62,19 @@ pub fn run(input: &str) -> i32 {
 
         let best_state = walk_tree(state);
         if let Some(states) = best_state {
-            println!("{}: {}", blueprint.id, states[0].inventory.geode);
-            geode_counts *= states[0].inventory.geode;
+            trace_builds(&states, 32, tracer);
+            tracer.emit(|| {
+                Step::new("blueprint")
+                    .with("id", blueprint.id)
+                    .with("geodes", states[0].inventory.geode)
+            });
+            geode_counts = arith::mul(19, geode_counts, states[0].inventory.geode);
         }
     }
 
     geode_counts
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 56i32 * 62i32);
-    }
+pub fn run(input: &str) -> Result<i64, ParseError> {
+    Ok(solve(&parse_blueprints(input)?))
 }
@@ -1,24 +1,26 @@
 use std::ops;
 
-use regex::Regex;
+use crate::arith;
+use crate::parse::{lines, Field, ParseError, Pattern};
+use crate::trace::{Step, Tracer};
 
 #[derive(Debug, Clone, Copy)]
 pub struct ResourceVec {
-    pub ore: i32,
-    pub clay: i32,
-    pub obsidian: i32,
-    pub geode: i32,
+    pub ore: i64,
+    pub clay: i64,
+    pub obsidian: i64,
+    pub geode: i64,
 }
 
 impl ResourceVec {
-    pub fn max(&self) -> i32 {
+    pub fn max(&self) -> i64 {
         *[self.ore, self.clay, self.obsidian, self.geode]
             .iter()
             .max()
             .unwrap()
     }
     #[allow(dead_code)]
-    pub fn min(&self) -> i32 {
+    pub fn min(&self) -> i64 {
         *[self.ore, self.clay, self.obsidian, self.geode]
             .iter()
             .min()
@@ -27,39 +29,39 @@ impl ResourceVec {
     // divides each field, rounding up
     pub fn div_round_up(&self, other: ResourceVec) -> ResourceVec {
         ResourceVec {
-            ore: if other.ore == 0 {
-                0
-            } else {
-                (self.ore as f32 / other.ore as f32).ceil() as i32
-            },
-            clay: if other.clay == 0 {
-                0
-            } else {
-                (self.clay as f32 / other.clay as f32).ceil() as i32
-            },
-            obsidian: if other.obsidian == 0 {
-                0
-            } else {
-                (self.obsidian as f32 / other.obsidian as f32).ceil() as i32
-            },
-            geode: if other.geode == 0 {
-                0
-            } else {
-                (self.geode as f32 / other.geode as f32).ceil() as i32
-            },
+            ore: div_ceil(self.ore, other.ore),
+            clay: div_ceil(self.clay, other.clay),
+            obsidian: div_ceil(self.obsidian, other.obsidian),
+            geode: div_ceil(self.geode, other.geode),
         }
     }
 }
 
+/**
+ * `a / b` rounded up, or 0 when `b` is 0. `a` can be negative, which `i64::div_ceil` doesn't
+ * support on stable Rust.
+ */
+fn div_ceil(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        return 0;
+    }
+    let quotient = arith::div(19, a, b);
+    if a % b != 0 && (a < 0) == (b < 0) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
 impl ops::Add for ResourceVec {
     type Output = ResourceVec;
 
     fn add(self, other: ResourceVec) -> ResourceVec {
         ResourceVec {
-            ore: self.ore + other.ore,
-            clay: self.clay + other.clay,
-            obsidian: self.obsidian + other.obsidian,
-            geode: self.geode + other.geode,
+            ore: arith::add(19, self.ore, other.ore),
+            clay: arith::add(19, self.clay, other.clay),
+            obsidian: arith::add(19, self.obsidian, other.obsidian),
+            geode: arith::add(19, self.geode, other.geode),
         }
     }
 }
@@ -69,18 +71,18 @@ impl ops::Sub for ResourceVec {
 
     fn sub(self, other: ResourceVec) -> ResourceVec {
         ResourceVec {
-            ore: self.ore - other.ore,
-            clay: self.clay - other.clay,
-            obsidian: self.obsidian - other.obsidian,
-            geode: self.geode - other.geode,
+            ore: arith::sub(19, self.ore, other.ore),
+            clay: arith::sub(19, self.clay, other.clay),
+            obsidian: arith::sub(19, self.obsidian, other.obsidian),
+            geode: arith::sub(19, self.geode, other.geode),
         }
     }
 }
 
-impl ops::Div<i32> for ResourceVec {
+impl ops::Div<i64> for ResourceVec {
     type Output = ResourceVec;
 
-    fn div(self, other: i32) -> ResourceVec {
+    fn div(self, other: i64) -> ResourceVec {
         ResourceVec {
             ore: self.ore / other,
             clay: self.clay / other,
@@ -119,22 +121,22 @@ impl ops::Div for ResourceVec {
     }
 }
 
-impl ops::Mul<i32> for ResourceVec {
+impl ops::Mul<i64> for ResourceVec {
     type Output = ResourceVec;
 
-    fn mul(self, other: i32) -> ResourceVec {
+    fn mul(self, other: i64) -> ResourceVec {
         ResourceVec {
-            ore: self.ore * other,
-            clay: self.clay * other,
-            obsidian: self.obsidian * other,
-            geode: self.geode * other,
+            ore: arith::mul(19, self.ore, other),
+            clay: arith::mul(19, self.clay, other),
+            obsidian: arith::mul(19, self.obsidian, other),
+            geode: arith::mul(19, self.geode, other),
         }
     }
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct Blueprint {
-    pub id: i32,
+    pub id: i64,
     pub ore_ingredients: ResourceVec,
     pub clay_ingredients: ResourceVec,
     pub obsidian_ingredients: ResourceVec,
@@ -142,7 +144,7 @@ pub struct Blueprint {
 }
 
 impl Blueprint {
-    pub fn max_ore(&self) -> i32 {
+    pub fn max_ore(&self) -> i64 {
         *[
             self.ore_ingredients.ore,
             self.clay_ingredients.ore,
@@ -153,7 +155,7 @@ impl Blueprint {
         .max()
         .unwrap()
     }
-    pub fn max_clay(&self) -> i32 {
+    pub fn max_clay(&self) -> i64 {
         *[
             self.ore_ingredients.clay,
             self.clay_ingredients.clay,
@@ -164,7 +166,7 @@ impl Blueprint {
         .max()
         .unwrap()
     }
-    pub fn max_obsidian(&self) -> i32 {
+    pub fn max_obsidian(&self) -> i64 {
         *[
             self.ore_ingredients.obsidian,
             self.clay_ingredients.obsidian,
@@ -176,7 +178,7 @@ impl Blueprint {
         .unwrap()
     }
     #[allow(dead_code)]
-    pub fn max_geode(&self) -> i32 {
+    pub fn max_geode(&self) -> i64 {
         *[
             self.ore_ingredients.geode,
             self.clay_ingredients.geode,
@@ -194,7 +196,7 @@ pub struct State {
     pub inventory: ResourceVec,
     pub inputs: ResourceVec,
     pub blueprint: Blueprint,
-    pub time_remaining: i32,
+    pub time_remaining: i64,
 }
 
 impl State {
@@ -320,60 +322,56 @@ impl State {
     }
 }
 
-pub fn parse_blueprints(input: &str) -> Vec<Blueprint> {
-    let mut blueprints = vec![];
-    let regex = Regex::new(r"Blueprint (?P<id>\d+): Each ore robot costs (?P<ore_ore>\d+) ore. Each clay robot costs (?P<clay_ore>\d+) ore. Each obsidian robot costs (?P<obsidian_ore>\d+) ore and (?P<obsidian_clay>\d+) clay. Each geode robot costs (?P<geode_ore>\d+) ore and (?P<geode_obsidian>\d+) obsidian.").unwrap();
-    for line in input.lines() {
-        let caps = regex.captures(line).unwrap();
-        blueprints.push(Blueprint {
-            id: caps.name("id").map_or(0, |m| m.as_str().parse().unwrap()),
-            ore_ingredients: ResourceVec {
-                ore: caps
-                    .name("ore_ore")
-                    .map_or(0, |m| m.as_str().parse().unwrap()),
-                clay: 0,
-                obsidian: 0,
-                geode: 0,
-            },
-            clay_ingredients: ResourceVec {
-                ore: caps
-                    .name("clay_ore")
-                    .map_or(0, |m| m.as_str().parse().unwrap()),
-                clay: 0,
-                obsidian: 0,
-                geode: 0,
-            },
-            obsidian_ingredients: ResourceVec {
-                ore: caps
-                    .name("obsidian_ore")
-                    .map_or(0, |m| m.as_str().parse().unwrap()),
-                clay: caps
-                    .name("obsidian_clay")
-                    .map_or(0, |m| m.as_str().parse().unwrap()),
-                obsidian: 0,
-                geode: 0,
-            },
-            geode_ingredients: ResourceVec {
-                ore: caps
-                    .name("geode_ore")
-                    .map_or(0, |m| m.as_str().parse().unwrap()),
-                clay: 0,
-                obsidian: caps
-                    .name("geode_obsidian")
-                    .map_or(0, |m| m.as_str().parse().unwrap()),
-                geode: 0,
-            },
+pub fn parse_blueprints(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let pattern = Pattern::new(
+        19,
+        "Blueprint {id}: \
+         Each ore robot costs {ore} ore. \
+         Each clay robot costs {ore} ore. \
+         Each obsidian robot costs {ore} ore and {clay} clay. \
+         Each geode robot costs {ore} ore and {obsidian} obsidian.",
+    );
+    lines(input)
+        .map(|(number, line)| {
+            let [id, ore_ore, clay_ore, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] =
+                pattern.parse(number, line)?;
+            let cost = |field: Field| field.integer::<i64>("a number");
+            Ok(Blueprint {
+                id: cost(id)?,
+                ore_ingredients: ResourceVec {
+                    ore: cost(ore_ore)?,
+                    clay: 0,
+                    obsidian: 0,
+                    geode: 0,
+                },
+                clay_ingredients: ResourceVec {
+                    ore: cost(clay_ore)?,
+                    clay: 0,
+                    obsidian: 0,
+                    geode: 0,
+                },
+                obsidian_ingredients: ResourceVec {
+                    ore: cost(obsidian_ore)?,
+                    clay: cost(obsidian_clay)?,
+                    obsidian: 0,
+                    geode: 0,
+                },
+                geode_ingredients: ResourceVec {
+                    ore: cost(geode_ore)?,
+                    clay: 0,
+                    obsidian: cost(geode_obsidian)?,
+                    geode: 0,
+                },
+            })
         })
-    }
-
-    blueprints
+        .collect()
 }
 
 pub fn wait_for_resource(
     target: ResourceVec,
     inventory: ResourceVec,
     input: ResourceVec,
-) -> Option<(i32, ResourceVec)> {
+) -> Option<(i64, ResourceVec)> {
     let diff = target - inventory;
     if diff.max() <= 0 {
         return Some((0, inventory));
@@ -416,10 +414,61 @@ pub fn build_robot_options(state: State) -> Vec<State> {
     states
 }
 
+/**
+ * Send the robots built along `states`, the best chain of states found by `walk_tree` (final state
+ * first), to `tracer`, with the minute each one is finished out of `minutes`.
+ */
+pub fn trace_builds(states: &[State], minutes: i64, tracer: &mut dyn Tracer) {
+    if !tracer.enabled() {
+        return;
+    }
+    for (old, new) in states.iter().rev().zip(states.iter().rev().skip(1)) {
+        let robot = if new.inputs.geode > old.inputs.geode {
+            "geode"
+        } else if new.inputs.obsidian > old.inputs.obsidian {
+            "obsidian"
+        } else if new.inputs.clay > old.inputs.clay {
+            "clay"
+        } else if new.inputs.ore > old.inputs.ore {
+            "ore"
+        } else {
+            // idling until time runs out
+            continue;
+        };
+        tracer.step(
+            Step::new("built robot")
+                .with("blueprint", new.blueprint.id)
+                .with("robot", robot)
+                .with("minute", minutes - new.time_remaining),
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_div_round_up() {
+        let resources = ResourceVec {
+            ore: 16_777_217,
+            clay: -5,
+            obsidian: 7,
+            geode: 3,
+        };
+        let divisors = ResourceVec {
+            ore: 1,
+            clay: 2,
+            obsidian: 2,
+            geode: 0,
+        };
+        let rounded = resources.div_round_up(divisors);
+        assert_eq!(
+            (rounded.ore, rounded.clay, rounded.obsidian, rounded.geode),
+            (16_777_217, -2, 4, 0)
+        );
+    }
+
     #[test]
     fn test_bot_rounding() {
         let state = State {
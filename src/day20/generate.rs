@@ -0,0 +1,21 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * An encrypted file of `size` numbers (at least 3), exactly one of which is 0. The numbers are
+ * within ±10,000, like the puzzle's, so most of them wrap around a small file many times.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let length = size.max(3);
+    let mut numbers = (1..length)
+        .map(|_| loop {
+            let number = rng.range(-10_000..=10_000);
+            if number != 0 {
+                break number;
+            }
+        })
+        .collect::<Vec<_>>();
+    numbers.insert(rng.below(length), 0);
+    numbers.iter().join("\n")
+}
@@ -0,0 +1,25 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Vec<i64>, ParseError> {
+        shared::parse_input(input)
+    }
+    fn part1(&self, encrypted: &Vec<i64>) -> i64 {
+        part1::solve(encrypted)
+    }
+    fn part2(&self, encrypted: &Vec<i64>) -> i64 {
+        part2::solve(encrypted)
+    }
+}
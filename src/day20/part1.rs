@@ -1,6 +1,8 @@
 use itertools::Itertools;
 
 use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
 
 /**
  * For each number in `encrypted`, shift the number based on its value. For example,
@@ -9,9 +11,9 @@ use super::shared::*;
  *
  * Surrounding elements should be shifted to make room.
  */
-pub fn mix(encrypted: Vec<i32>) -> Vec<i32> {
+pub fn mix(encrypted: Vec<i64>) -> Vec<i64> {
     let mut mixed = encrypted.iter().enumerate().collect_vec();
-    let rotate_len = (encrypted.len() - 1) as i32;
+    let rotate_len = (encrypted.len() - 1) as i64;
     for (original_index, num) in encrypted.iter().enumerate() {
         if num == &0 {
             continue;
@@ -21,9 +23,7 @@ pub fn mix(encrypted: Vec<i32>) -> Vec<i32> {
             .find_position(|p| p.0 == original_index)
             .unwrap()
             .0;
-        let mut new_index = current_index as i32 + num;
-
-        new_index = (new_index + rotate_len * 2) % rotate_len;
+        let new_index = (current_index as i64 + num).rem_euclid(rotate_len);
 
         let pulled = mixed.remove(current_index);
         mixed.insert(new_index as usize, pulled);
@@ -31,29 +31,27 @@ pub fn mix(encrypted: Vec<i32>) -> Vec<i32> {
     mixed.iter().map(|(_, p)| p).cloned().cloned().collect_vec()
 }
 
-pub fn coordinates(encrypted: Vec<i32>) -> i32 {
+pub fn coordinates(encrypted: Vec<i64>) -> i64 {
     let start = encrypted.iter().find_position(|p| p == &&0).unwrap().0;
     let numbers = [start + 1000, start + 2000, start + 3000]
         .iter()
         .map(|p| encrypted[p % encrypted.len()])
         .collect::<Vec<_>>();
-    println!("{:?}", numbers);
-    numbers.iter().sum()
+    arith::sum(20, numbers)
 }
 
-pub fn run(input: &str) -> i32 {
-    let encrypted = parse_input(input);
-    let mixed = mix(encrypted);
-    coordinates(mixed)
+pub fn solve(encrypted: &[i64]) -> i64 {
+    coordinates(mix_rounds(encrypted, 1))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/**
+ * Reference version of [`solve`], which mixes with [`mix`].
+ */
+pub fn solve_naive(encrypted: &[i64]) -> i64 {
+    let mixed = mix(encrypted.to_vec());
+    coordinates(mixed)
+}
 
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 3i32);
-    }
+pub fn run(input: &str) -> Result<i64, ParseError> {
+    Ok(solve(&parse_input(input)?))
 }
@@ -1,6 +1,8 @@
 use itertools::Itertools;
 
 use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
 
 const DECRYPTION_KEY: i64 = 811589153;
 
@@ -19,11 +21,7 @@ pub fn mix(encrypted: Vec<(usize, i64)>) -> Vec<(usize, i64)> {
             .iter()
             .find_position(|p| p.0 == original_index)
             .unwrap();
-        let mut new_index = current_index as i64 + num;
-
-        new_index = (new_index + (rotate_len * DECRYPTION_KEY * 2)) % rotate_len;
-
-        // println!("{} -> {}", current_index, new_index);
+        let new_index = (current_index as i64 + num).rem_euclid(rotate_len);
 
         let pulled = mixed.remove(current_index);
         mixed.insert(new_index as usize, pulled);
@@ -37,30 +35,32 @@ pub fn coordinates(encrypted: Vec<i64>) -> i64 {
         .iter()
         .map(|p| encrypted[p % encrypted.len()])
         .collect::<Vec<_>>();
-    println!("{:?}", numbers);
-    numbers.iter().sum()
+    arith::sum(20, numbers)
+}
+
+pub fn solve(encrypted: &[i64]) -> i64 {
+    let numbers = encrypted
+        .iter()
+        .map(|&n| arith::mul(20, n, DECRYPTION_KEY))
+        .collect_vec();
+    coordinates(mix_rounds(&numbers, 10))
 }
 
-pub fn run(input: &str) -> i64 {
-    let mut encrypted = parse_input(input)
+/**
+ * Reference version of [`solve`], which mixes with [`mix`].
+ */
+pub fn solve_naive(encrypted: &[i64]) -> i64 {
+    let mut encrypted = encrypted
         .iter()
-        .map(|p| (*p as i64) * DECRYPTION_KEY)
+        .map(|&p| arith::mul(20, p, DECRYPTION_KEY))
         .enumerate()
         .collect_vec();
     for _ in 0..10 {
         encrypted = mix(encrypted);
-        println!("{:?}", encrypted);
     }
     coordinates(encrypted.iter().map(|p| p.1).collect_vec())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 1623178306i64);
-    }
+pub fn run(input: &str) -> Result<i64, ParseError> {
+    Ok(solve(&parse_input(input)?))
 }
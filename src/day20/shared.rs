@@ -1,6 +1,64 @@
+use crate::parse::ParseError;
+
 /**
  * Load lines with numbers from the input file
  */
-pub fn parse_input(input: &str) -> Vec<i32> {
-    input.lines().map(|line| line.parse().unwrap()).collect()
+pub fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.parse()
+                .map_err(|_| ParseError::line(20, index + 1, line, "a number"))
+        })
+        .collect()
+}
+
+/**
+ * Mix the numbers `rounds` times, moving each one (in its original order) as many places as its
+ * value, and return them in their mixed order.
+ *
+ * The numbers are kept in blocks of about √n, so finding and moving one only walks a block and the
+ * list of blocks instead of the whole file. Moved numbers make some blocks bigger, so every √n
+ * moves the blocks are evened out again.
+ */
+pub fn mix_rounds(numbers: &[i64], rounds: usize) -> Vec<i64> {
+    let len = numbers.len();
+    if len < 2 {
+        return numbers.to_vec();
+    }
+    let block_size = (len as f64).sqrt() as usize;
+    let mut blocks = vec![(0..len).collect::<Vec<_>>()];
+    let mut block_of = vec![0; len];
+    let mut moves = 0;
+    for _ in 0..rounds {
+        for (item, number) in numbers.iter().enumerate() {
+            if moves % block_size == 0 {
+                let order = blocks.concat();
+                blocks = order.chunks(block_size).map(<[usize]>::to_vec).collect();
+                for (block, items) in blocks.iter().enumerate() {
+                    for &item in items {
+                        block_of[item] = block;
+                    }
+                }
+            }
+            moves += 1;
+
+            let block = block_of[item];
+            let offset = blocks[block].iter().position(|&i| i == item).unwrap();
+            let index = blocks[..block].iter().map(Vec::len).sum::<usize>() + offset;
+            blocks[block].remove(offset);
+
+            let mut new_index = (index as i64 + number).rem_euclid(len as i64 - 1) as usize;
+            for (block, items) in blocks.iter_mut().enumerate() {
+                if new_index <= items.len() {
+                    items.insert(new_index, item);
+                    block_of[item] = block;
+                    break;
+                }
+                new_index -= items.len();
+            }
+        }
+    }
+    blocks.concat().iter().map(|&item| numbers[item]).collect()
 }
@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * About `size` monkeys (at least 3) yelling numbers or doing sums with them.
+ *
+ * The expression is built from the top down, splitting each monkey's value between the two it
+ * listens to so that every division is exact. `root`'s two sides start out equal, so `humn` is
+ * already yelling the number part 2 looks for.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut builder = Builder {
+        rng,
+        names: HashSet::from(["root".to_string(), "humn".to_string()]),
+        lines: vec![],
+    };
+    let value = builder.rng.range(1000..=100_000);
+    let operations = size.max(3) / 2;
+    let human_side = builder.rng.below(operations);
+    let left = builder.monkey(value, human_side, true);
+    let right = builder.monkey(value, operations - 1 - human_side, false);
+    let (left, right) = if builder.rng.chance(0.5) {
+        (left, right)
+    } else {
+        (right, left)
+    };
+    builder.lines.push(format!("root: {} + {}", left, right));
+
+    let mut lines = builder.lines;
+    builder.rng.shuffle(&mut lines);
+    lines.join("\n")
+}
+
+struct Builder<'a> {
+    rng: &'a mut Rng,
+    names: HashSet<String>,
+    lines: Vec<String>,
+}
+
+impl Builder<'_> {
+    fn name(&mut self) -> String {
+        loop {
+            let name = (0..4)
+                .map(|_| (b'a' + self.rng.below(26) as u8) as char)
+                .collect::<String>();
+            if self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    /**
+     * Add a monkey that yells `value`, using `operations` operations below it. Returns its name.
+     */
+    fn monkey(&mut self, value: i64, operations: usize, human: bool) -> String {
+        if operations == 0 {
+            let name = if human {
+                "humn".to_string()
+            } else {
+                self.name()
+            };
+            self.lines.push(format!("{}: {}", name, value));
+            return name;
+        }
+
+        let small = self.rng.range(2..=20);
+        let divisors = (2..=20).filter(|d| value % d == 0).collect_vec();
+        let (operator, a, b) = match self.rng.below(4) {
+            0 if value >= 2 => {
+                let a = self.rng.range(1..=value - 1);
+                ('+', a, value - a)
+            }
+            1 if !divisors.is_empty() => {
+                let divisor = *self.rng.pick(&divisors);
+                ('*', value / divisor, divisor)
+            }
+            2 if value < 1_000_000_000 => ('/', value * small, small),
+            _ => ('-', value + small, small),
+        };
+        // share the rest of the operations out, with the human on one side
+        let left_operations = self.rng.below(operations);
+        let right_operations = operations - 1 - left_operations;
+        let human_left = human && self.rng.chance(0.5);
+        let name = self.name();
+        let left = self.monkey(a, left_operations, human_left);
+        let right = self.monkey(b, right_operations, human && !human_left);
+        self.lines
+            .push(format!("{}: {} {} {}", name, left, operator, right));
+        name
+    }
+}
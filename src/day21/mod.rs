@@ -0,0 +1,30 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+use shared::Monkey;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Parsed = Vec<Monkey>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Monkey>, ParseError> {
+        shared::parse_input(input)
+    }
+    fn part1(&self, monkeys: &Vec<Monkey>) -> i64 {
+        part1::solve(monkeys)
+    }
+    fn part2(&self, monkeys: &Vec<Monkey>) -> i64 {
+        part2::solve(monkeys)
+    }
+    fn check_record(&self, line: &str) -> Result<(), ParseError> {
+        shared::parse_input_unchecked(line).map(|_| ())
+    }
+}
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
 use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
 
 fn run_monkey(
     monkey: Monkey,
@@ -32,16 +34,16 @@ fn run_monkey(
             }
         }
         Ops::Add(op1, op2) => {
-            resolve(op1, op2, &|a, b| a + b);
+            resolve(op1, op2, &|a, b| arith::add(21, a, b));
         }
         Ops::Sub(op1, op2) => {
-            resolve(op1, op2, &|a, b| a - b);
+            resolve(op1, op2, &|a, b| arith::sub(21, a, b));
         }
         Ops::Mul(op1, op2) => {
-            resolve(op1, op2, &|a, b| a * b);
+            resolve(op1, op2, &|a, b| arith::mul(21, a, b));
         }
         Ops::Div(op1, op2) => {
-            resolve(op1, op2, &|a, b| a / b);
+            resolve(op1, op2, &|a, b| arith::div(21, a, b));
         }
     }
 }
@@ -60,18 +62,10 @@ fn run_monkeys(monkeys: Vec<Monkey>) -> i64 {
     0
 }
 
-pub fn run(input: &str) -> i64 {
-    let monkeys = parse_input(input);
-    run_monkeys(monkeys)
+pub fn solve(monkeys: &[Monkey]) -> i64 {
+    run_monkeys(monkeys.to_vec())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 152i64);
-    }
+pub fn run(input: &str) -> Result<i64, ParseError> {
+    Ok(solve(&parse_input(input)?))
 }
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
 use super::shared::*;
+use crate::arith;
+use crate::parse::ParseError;
 
 #[derive(Debug, Clone)]
 enum Operation {
@@ -157,16 +159,23 @@ fn reduce(string: &String, variables: &HashMap<String, Operation>) -> i64 {
     let op = variables.get(string);
     match op {
         Some(Operation::Num(num)) => *num,
-        Some(Operation::Add(op1, op2)) => reduce(op1, variables) + reduce(op2, variables),
-        Some(Operation::Sub(op1, op2)) => reduce(op1, variables) - reduce(op2, variables),
-        Some(Operation::Mul(op1, op2)) => reduce(op1, variables) * reduce(op2, variables),
-        Some(Operation::Div(op1, op2)) => reduce(op1, variables) / reduce(op2, variables),
+        Some(Operation::Add(op1, op2)) => {
+            arith::add(21, reduce(op1, variables), reduce(op2, variables))
+        }
+        Some(Operation::Sub(op1, op2)) => {
+            arith::sub(21, reduce(op1, variables), reduce(op2, variables))
+        }
+        Some(Operation::Mul(op1, op2)) => {
+            arith::mul(21, reduce(op1, variables), reduce(op2, variables))
+        }
+        Some(Operation::Div(op1, op2)) => {
+            arith::div(21, reduce(op1, variables), reduce(op2, variables))
+        }
         _ => panic!("invalid variable"),
     }
 }
 
-pub fn run(input: &str) -> i64 {
-    let monkeys = parse_input(input);
+pub fn solve(monkeys: &[Monkey]) -> i64 {
     let mut operations = HashMap::new();
 
     for monkey in monkeys {
@@ -204,13 +213,6 @@ pub fn run(input: &str) -> i64 {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 301i64);
-    }
+pub fn run(input: &str) -> Result<i64, ParseError> {
+    Ok(solve(&parse_input(input)?))
 }
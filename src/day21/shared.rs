@@ -1,4 +1,6 @@
-use regex::Regex;
+use std::collections::HashMap;
+
+use crate::parse::{lines, ParseError, Pattern};
 
 #[derive(Debug, Clone)]
 pub enum Ops {
@@ -18,33 +20,203 @@ pub struct Monkey {
 /**
  * Load lines with monkey jobs from file
  *
- * Lines are formatted like `name: n` for Yell ops or `name: nam2 * nam3` for other ops
+ * Lines are formatted like `name: n` for Yell ops or `name: nam2 * nam3` for other ops. Every name
+ * a job uses must belong to exactly one monkey, and no job can depend on its own result. There
+ * must be a `root` with a job that, directly or not, waits on `humn`.
  */
-pub fn parse_input(input: &str) -> Vec<Monkey> {
-    let mut monkeys = vec![];
-    let re_math = Regex::new(r"(.{4}): (.{4}) ([+\-/\*]) (.{4})").unwrap();
-    let re_yell = Regex::new(r"(.{4}): (-?\d+)").unwrap();
-    for line in input.lines() {
-        if let Some(caps) = re_math.captures(line) {
-            let name = caps.get(1).unwrap().as_str().to_string();
-            let op_type = caps.get(3).unwrap().as_str();
-            let operand1 = caps.get(2).unwrap().as_str().to_string();
-            let operand2 = caps.get(4).unwrap().as_str().to_string();
-            let op = match op_type {
-                "+" => Ops::Add(operand1, operand2),
-                "-" => Ops::Sub(operand1, operand2),
-                "*" => Ops::Mul(operand1, operand2),
-                "/" => Ops::Div(operand1, operand2),
-                _ => panic!("Unknown op type {}", op_type),
+pub fn parse_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let monkeys = parse_input_unchecked(input)?;
+
+    // each monkey's line, and the names its job uses, as slices of the line for errors
+    let jobs = lines(input)
+        .map(|(number, line)| {
+            // the line has already parsed, so it's `name: a op b` or `name: n`
+            let words = line.split(' ').collect::<Vec<_>>();
+            let operands = match words[..] {
+                [_, operand1, _, operand2] => vec![operand1, operand2],
+                _ => vec![],
+            };
+            (number, line, &line[..line.find(':').unwrap()], operands)
+        })
+        .collect::<Vec<_>>();
+    let mut index = HashMap::new();
+    for (position, &(number, line, name, _)) in jobs.iter().enumerate() {
+        if index.insert(name, position).is_some() {
+            return Err(ParseError::within(
+                21,
+                number,
+                line,
+                name,
+                "a name no other monkey has",
+            ));
+        }
+    }
+    for &(number, line, _, ref operands) in &jobs {
+        for &operand in operands {
+            if !index.contains_key(operand) {
+                return Err(ParseError::within(
+                    21,
+                    number,
+                    line,
+                    operand,
+                    "the name of a monkey",
+                ));
+            }
+        }
+    }
+    for name in ["root", "humn"] {
+        if !index.contains_key(name) {
+            return Err(ParseError::end_of_input(
+                21,
+                jobs.len(),
+                format!("a monkey named {}", name),
+            ));
+        }
+    }
+
+    // depth-first through each job's operands, looking for one that leads back to a monkey whose
+    // job is still being followed
+    let mut finished = vec![false; jobs.len()];
+    let mut following = vec![false; jobs.len()];
+    for start in 0..jobs.len() {
+        if finished[start] {
+            continue;
+        }
+        following[start] = true;
+        let mut stack = vec![(start, 0)];
+        while let Some(&(position, next)) = stack.last() {
+            let (number, line, _, ref operands) = jobs[position];
+            let Some(&operand) = operands.get(next) else {
+                following[position] = false;
+                finished[position] = true;
+                stack.pop();
+                continue;
             };
-            monkeys.push(Monkey { name, op });
-        } else if let Some(caps) = re_yell.captures(line) {
-            let name = caps.get(1).unwrap().as_str().to_string();
-            let op = Ops::Yell(caps.get(2).unwrap().as_str().parse().unwrap());
-            monkeys.push(Monkey { name, op });
-        } else {
-            panic!("Unknown line format: {}", line);
+            stack.last_mut().unwrap().1 += 1;
+            let operand_position = index[operand];
+            if following[operand_position] {
+                return Err(ParseError::within(
+                    21,
+                    number,
+                    line,
+                    operand,
+                    "a monkey that doesn't wait for this one",
+                ));
+            }
+            if !finished[operand_position] {
+                following[operand_position] = true;
+                stack.push((operand_position, 0));
+            }
+        }
+    }
+
+    // part 2 solves root's equation for humn, so root has to be one and humn has to be in it
+    let (number, line, _, ref operands) = jobs[index["root"]];
+    if operands.is_empty() {
+        let job = &line[line.find(": ").unwrap() + 2..];
+        return Err(ParseError::within(
+            21,
+            number,
+            line,
+            job,
+            "a job like `aaaa + bbbb` for root",
+        ));
+    }
+    let mut reached = vec![false; jobs.len()];
+    let mut stack = vec![index["root"]];
+    while let Some(position) = stack.pop() {
+        if !std::mem::replace(&mut reached[position], true) {
+            stack.extend(jobs[position].3.iter().map(|operand| index[operand]));
         }
     }
-    monkeys
+    if !reached[index["humn"]] {
+        return Err(ParseError::line(
+            21,
+            number,
+            line,
+            "a job for root that waits on humn",
+        ));
+    }
+
+    Ok(monkeys)
+}
+
+/**
+ * Like [`parse_input`], but only checking each line's format, so a line can be checked on its own.
+ */
+pub fn parse_input_unchecked(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let line_pattern = Pattern::new(21, "{name}: {job}");
+    let math_pattern = Pattern::new(21, "{name} {op} {name}");
+    lines(input)
+        .map(|(number, line)| {
+            let [name, job] = line_pattern.parse(number, line)?;
+            let op = if job.text().contains(' ') {
+                let [operand1, op, operand2] = math_pattern.parse_field(job)?;
+                let operands = (operand1.text().to_string(), operand2.text().to_string());
+                match op.text() {
+                    "+" => Ops::Add(operands.0, operands.1),
+                    "-" => Ops::Sub(operands.0, operands.1),
+                    "*" => Ops::Mul(operands.0, operands.1),
+                    "/" => Ops::Div(operands.0, operands.1),
+                    _ => return Err(op.error("+, -, * or /")),
+                }
+            } else {
+                Ops::Yell(job.signed("a number")?)
+            };
+            Ok(Monkey {
+                name: name.text().to_string(),
+                op,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_names() {
+        let sample = include_str!("inputs/sample.txt");
+        let error =
+            parse_input(&sample.replace("sjmn: drzm * dbpl", "sjmn: drzm * zzzz")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 21, line 10, column 14: expected the name of a monkey, found \"zzzz\""
+        );
+
+        let error = parse_input(&sample.replace("humn", "hmmm")).unwrap_err();
+        assert_eq!(error.line, 16);
+        assert_eq!(error.expected, "a monkey named humn");
+        assert!(parse_input_unchecked("efgh: zzzz * abcd").is_ok());
+    }
+
+    #[test]
+    fn test_cycles() {
+        let input = "root: aaaa + bbbb\naaaa: bbbb * humn\nbbbb: 1\nhumn: 2";
+        assert!(parse_input(input).is_ok());
+
+        let error = parse_input(&input.replace("bbbb: 1", "bbbb: root - humn")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 21, line 3, column 7: expected a monkey that doesn't wait for this one, found \
+             \"root\""
+        );
+        let error = parse_input(&input.replace("humn: 2", "humn: humn / bbbb")).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 7));
+
+        let error = parse_input(&input.replace("root: aaaa + bbbb", "root: 5")).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 7, "5"));
+        let error = parse_input(&input.replace("bbbb * humn", "bbbb * bbbb")).unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (1, "a job for root that waits on humn")
+        );
+
+        let error = parse_input(&format!("{}\nbbbb: 3", input)).unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (5, "a name no other monkey has")
+        );
+    }
 }
@@ -0,0 +1,64 @@
+use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * Cube nets, as the faces' positions in a grid of faces: the sample's layout and the usual real
+ * input's.
+ */
+const NETS: [&[(usize, usize)]; 2] = [
+    &[(2, 0), (0, 1), (1, 1), (2, 1), (2, 2), (3, 2)],
+    &[(1, 0), (2, 0), (1, 1), (0, 2), (1, 2), (0, 3)],
+];
+
+/**
+ * A cube net with faces `size` tiles across (at least 2) and a few walls, followed by a path of
+ * `size * 2` moves.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let face = size.max(2);
+    let net = *rng.pick(&NETS);
+    let rows = net.iter().map(|(_, y)| y).max().unwrap() + 1;
+
+    let mut lines = (0..rows * face)
+        .map(|y| {
+            let width = net
+                .iter()
+                .filter(|(_, row)| *row == y / face)
+                .map(|(x, _)| (x + 1) * face)
+                .max()
+                .unwrap();
+            (0..width)
+                .map(|x| {
+                    if !net.contains(&(x / face, y / face)) {
+                        ' '
+                    } else if rng.chance(0.1) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    // the path starts at the leftmost open tile of the top row, so make sure there is one
+    let start = lines[0].iter().position(|&c| c != ' ').unwrap();
+    lines[0][start] = '.';
+
+    let path = (0..size.max(2) * 2)
+        .map(|i| {
+            let steps = rng.range(1..=face as i64 * 2).to_string();
+            if i == 0 {
+                steps
+            } else {
+                format!("{}{}", rng.pick(&["L", "R"]), steps)
+            }
+        })
+        .join("");
+
+    lines
+        .iter()
+        .map(|line| line.iter().collect::<String>())
+        .chain(["".to_string(), path])
+        .join("\n")
+}
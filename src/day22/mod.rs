@@ -0,0 +1,39 @@
+pub mod generate;
+pub mod part1;
+// mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::{Solution, Unsolved};
+use crate::visualize::Visualizer;
+
+use shared::{Instructions, Map};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Parsed = (Map, Vec<Instructions>);
+    type Part1 = usize;
+    // part 2 (folding the map into a cube) isn't finished yet
+    type Part2 = Unsolved;
+
+    fn parse(&self, input: &str) -> Result<(Map, Vec<Instructions>), ParseError> {
+        shared::parse_input(input)
+    }
+    fn part1(&self, (map, instructions): &(Map, Vec<Instructions>)) -> usize {
+        part1::solve(map, instructions)
+    }
+    fn part2(&self, _: &(Map, Vec<Instructions>)) -> Unsolved {
+        Unsolved
+    }
+}
+
+/**
+ * Watch the walk around the map, one frame per instruction. Only part 1 is solved, so only part 1
+ * can be visualized.
+ */
+pub fn visualize(input: &str, _: u8, visualizer: &mut dyn Visualizer) -> Result<(), ParseError> {
+    let (map, instructions) = shared::parse_input(input)?;
+    part1::solve_with(&map, &instructions, visualizer);
+    Ok(())
+}
@@ -1,23 +1,35 @@
 use super::shared::*;
+use crate::parse::ParseError;
+use crate::visualize::{Frame, NoVisualizer, Visualizer};
 
-pub fn run(input: &str) -> usize {
-    let (map, instructions) = parse_input(input);
-    let mut player = PlayerState::initialize(&map);
+pub fn solve(map: &Map, instructions: &[Instructions]) -> usize {
+    solve_with(map, instructions, &mut NoVisualizer)
+}
+
+/**
+ * Solve, sending a frame of the map after each instruction.
+ */
+pub fn solve_with(
+    map: &Map,
+    instructions: &[Instructions],
+    visualizer: &mut dyn Visualizer,
+) -> usize {
+    let mut player = PlayerState::initialize(map);
 
-    for inst in instructions {
-        player.follow_instruction(&inst, &map);
+    for (index, inst) in instructions.iter().enumerate() {
+        player.follow_instruction(inst, map);
+        visualizer.emit(|| {
+            Frame::new(
+                format!("Instruction {}: {:?}", index + 1, inst),
+                render_map(map, &player),
+            )
+        });
     }
 
     player.password()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 6032usize);
-    }
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    let (map, instructions) = parse_input(input)?;
+    Ok(solve(&map, &instructions))
 }
@@ -1,14 +1,15 @@
 use super::shared::*;
+use crate::parse::ParseError;
 
-pub fn run(input: &str) -> usize {
-    let (map, instructions) = parse_input(input);
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    let (map, instructions) = parse_input(input)?;
     let mut player = PlayerState::initialize(&map);
 
     for inst in instructions {
         player.follow_instruction(&inst, &map);
     }
 
-    player.password()
+    Ok(player.password())
 }
 
 #[cfg(test)]
@@ -18,6 +19,6 @@ mod tests {
     #[test]
     fn test_input() {
         let demo = include_str!("inputs/sample.txt");
-        assert_eq!(run(demo), 6032usize);
+        assert_eq!(run(demo).unwrap(), 6032usize);
     }
 }
@@ -1,5 +1,8 @@
 use itertools::Itertools;
-use regex::Regex;
+
+use crate::arith;
+use crate::grid::Grid;
+use crate::parse::{Field, ParseError};
 
 #[derive(Debug)]
 pub enum Instructions {
@@ -59,14 +62,14 @@ impl PlayerState {
                         let mut next_y = if self.y > 0 {
                             self.y - 1
                         } else {
-                            map.len() - 1
+                            map.height() - 1
                         };
-                        if map[next_y][self.x] == ' ' {
+                        if map[(self.x, next_y)] == ' ' {
                             // wrap to start
                             let (origin, slice) = slice_x(map, self.x);
                             next_y = origin + slice.len() - 1;
                         }
-                        let next_tile = map[next_y][self.x];
+                        let next_tile = map[(self.x, next_y)];
                         if next_tile == '#' {
                             return;
                         } else if next_tile == '.' {
@@ -78,12 +81,12 @@ impl PlayerState {
                 Direction::Down => {
                     for _ in 0..*n {
                         let mut next_y = self.y + 1;
-                        if next_y >= map.len() || map[next_y][self.x] == ' ' {
+                        if next_y >= map.height() || map[(self.x, next_y)] == ' ' {
                             // wrap to start
                             let (origin, _) = slice_x(map, self.x);
                             next_y = origin;
                         }
-                        let next_tile = map[next_y][self.x];
+                        let next_tile = map[(self.x, next_y)];
                         if next_tile == '#' {
                             return;
                         } else if next_tile == '.' {
@@ -97,14 +100,14 @@ impl PlayerState {
                         let mut next_x = if self.x > 0 {
                             self.x - 1
                         } else {
-                            map[0].len() - 1
+                            map.width() - 1
                         };
-                        if map[self.y][next_x] == ' ' {
+                        if map[(next_x, self.y)] == ' ' {
                             // wrap to start
                             let (origin, slice) = slice_y(map, self.y);
                             next_x = origin + slice.len() - 1;
                         }
-                        let next_tile = map[self.y][next_x];
+                        let next_tile = map[(next_x, self.y)];
                         if next_tile == '#' {
                             return;
                         } else if next_tile == '.' {
@@ -116,12 +119,12 @@ impl PlayerState {
                 Direction::Right => {
                     for _ in 0..*n {
                         let mut next_x = self.x + 1;
-                        if next_x >= map[0].len() || map[self.y][next_x] == ' ' {
+                        if next_x >= map.width() || map[(next_x, self.y)] == ' ' {
                             // wrap to start
                             let (origin, _) = slice_y(map, self.y);
                             next_x = origin;
                         }
-                        let next_tile = map[self.y][next_x];
+                        let next_tile = map[(next_x, self.y)];
                         if next_tile == '#' {
                             return;
                         } else if next_tile == '.' {
@@ -135,23 +138,24 @@ impl PlayerState {
         }
     }
     pub fn password(&self) -> usize {
-        1000 * (self.y + 1) + 4 * (self.x + 1) + (self.dir as usize)
+        let row = arith::mul(22, 1000, self.y + 1);
+        let column = arith::mul(22, 4, self.x + 1);
+        arith::add(22, arith::add(22, row, column), self.dir as usize)
     }
     pub fn follow_instruction(&mut self, inst: &Instructions, map: &Map) {
         match inst {
             Instructions::Forward(_) => self.move_forward(inst, map),
             _ => self.rotate(inst),
         }
-        // println!("{}\n", render_map(map, self));
     }
 }
 
-pub type Map = Vec<Vec<char>>;
+pub type Map = Grid<char>;
 
 pub fn slice_y(map: &Map, y: usize) -> (usize, Vec<char>) {
     let mut slice = vec![];
     let mut origin = None;
-    for (i, c) in map[y].iter().enumerate() {
+    for (i, c) in map.row(y).iter().enumerate() {
         if c != &' ' {
             slice.push(*c);
             if origin.is_none() {
@@ -164,9 +168,9 @@ pub fn slice_y(map: &Map, y: usize) -> (usize, Vec<char>) {
 pub fn slice_x(map: &Map, x: usize) -> (usize, Vec<char>) {
     let mut slice = vec![];
     let mut origin = None;
-    for (i, c) in map.iter().map(|row| row[x]).enumerate() {
-        if c != ' ' {
-            slice.push(c);
+    for (i, c) in map.column(x).enumerate() {
+        if c != &' ' {
+            slice.push(*c);
             if origin.is_none() {
                 origin = Some(i);
             }
@@ -174,6 +178,7 @@ pub fn slice_x(map: &Map, x: usize) -> (usize, Vec<char>) {
     }
     (origin.unwrap(), slice)
 }
+#[allow(dead_code)]
 pub fn max_dist(slice: &Vec<char>, offset: usize) -> Option<usize> {
     let mut rotated = (*slice).clone();
     rotated.rotate_left(offset);
@@ -194,58 +199,54 @@ pub fn starting_point(map: &Map) -> (usize, usize) {
 /**
  * Load map and path directions
  */
-pub fn parse_input(input: &str) -> (Map, Vec<Instructions>) {
-    let lines = input.lines();
-    let mut map = Vec::new();
-    // load map first
-    for line in lines.take_while(|line| !line.is_empty()) {
-        map.push(line.chars().collect_vec());
-    }
-    // pad map, if needed
-    let max_len = map.iter().map(|row| row.len()).max().unwrap();
-    for row in map.iter_mut() {
-        while row.len() < max_len {
-            row.push(' ');
-        }
-    }
-    // now load path
-    let re = Regex::new(r"(?:(R)|(L)|(\d+))").unwrap();
-    let caps = re.captures_iter(input.lines().last().unwrap());
-    let mut path = Vec::new();
-    for cap in caps {
-        if cap.get(1).is_some() {
-            path.push(Instructions::Right());
-        } else if cap.get(2).is_some() {
-            path.push(Instructions::Left());
-        } else if let Some(dir) = cap.get(3) {
-            path.push(Instructions::Forward(dir.as_str().parse().unwrap()));
-        }
+pub fn parse_input(input: &str) -> Result<(Map, Vec<Instructions>), ParseError> {
+    // load map first; shorter rows are padded with spaces
+    let map = Grid::parse(
+        input.lines().take_while(|line| !line.is_empty()),
+        |(x, y), c| match c {
+            ' ' | '.' | '#' => Ok(c),
+            _ => Err(ParseError::new(
+                22,
+                y + 1,
+                x + 1,
+                c.to_string(),
+                "a space, . or #",
+            )),
+        },
+    )?;
+    if map.height() == 0 {
+        return Err(ParseError::line(22, 1, "", "a row of the map"));
     }
+    // now load path, which comes after the map and a blank line
+    let path_line = map.height() + 2;
+    let instructions = input.lines().nth(path_line - 1).ok_or_else(|| {
+        ParseError::end_of_input(22, input.lines().count(), "a path like `10R5L5`")
+    })?;
+    let path = Field::line(22, path_line, instructions)
+        .tokens()
+        .iter()
+        .map(|token| match token.text() {
+            "R" => Ok(Instructions::Right()),
+            "L" => Ok(Instructions::Left()),
+            _ => Ok(Instructions::Forward(token.integer("R, L or a number")?)),
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
 
-    (map, path)
+    Ok((map, path))
 }
 
+/**
+ * Render the map with the player drawn as an arrow pointing the way they're facing.
+ */
 pub fn render_map(map: &Map, player: &PlayerState) -> String {
-    map.iter()
-        .enumerate()
-        .map(|(y, row)| {
-            row.iter()
-                .enumerate()
-                .map(|(x, c)| {
-                    if y == player.y && x == player.x {
-                        match player.dir {
-                            Direction::Up => '^',
-                            Direction::Down => 'v',
-                            Direction::Left => '<',
-                            Direction::Right => '>',
-                        }
-                    } else {
-                        *c
-                    }
-                })
-                .collect::<String>()
-        })
-        .join("\n")
+    let mut map = map.clone();
+    map[(player.x, player.y)] = match player.dir {
+        Direction::Up => '^',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+        Direction::Right => '>',
+    };
+    map.to_string()
 }
 
 #[cfg(test)]
@@ -256,7 +257,7 @@ mod tests {
     fn test_move_right() {
         let demo = include_str!("inputs/sample.txt");
 
-        let (map, _) = parse_input(demo);
+        let (map, _) = parse_input(demo).unwrap();
 
         let mut player = PlayerState {
             x: 8,
@@ -276,7 +277,7 @@ mod tests {
     fn test_move_left() {
         let demo = include_str!("inputs/sample.txt");
 
-        let (map, _) = parse_input(demo);
+        let (map, _) = parse_input(demo).unwrap();
 
         let mut player = PlayerState {
             x: 10,
@@ -296,7 +297,7 @@ mod tests {
     fn test_move_down() {
         let demo = include_str!("inputs/sample.txt");
 
-        let (map, _) = parse_input(demo);
+        let (map, _) = parse_input(demo).unwrap();
 
         let mut player = PlayerState {
             x: 3,
@@ -316,7 +317,7 @@ mod tests {
     fn test_move_up() {
         let demo = include_str!("inputs/sample.txt");
 
-        let (map, _) = parse_input(demo);
+        let (map, _) = parse_input(demo).unwrap();
 
         let mut player = PlayerState {
             x: 2,
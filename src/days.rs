@@ -0,0 +1,378 @@
+use std::collections::HashMap;
+
+use super::parse::ParseError;
+use super::random::Rng;
+use super::solution::DynSolution;
+use super::trace::Tracer;
+use super::validate::Records;
+use super::visualize::Visualizer;
+use super::*;
+
+/**
+ * A single puzzle day, with its bundled input and a way to build its solution.
+ *
+ * The solution is built per run so days with parameters (like day 15's row) can be configured
+ * from the command line.
+ */
+pub struct Day {
+    pub day: u8,
+    pub input: &'static str,
+    /// How the input splits into records that can be checked on their own
+    pub records: Records,
+    pub params: &'static [Param],
+    pub solution: fn(&Params) -> Box<dyn DynSolution>,
+    /// Generates a random input of roughly the given size (what that means depends on the day)
+    pub generate: fn(&mut Rng, usize) -> String,
+    /// Frames of the simulation, for the days that have one worth watching
+    pub visualization: Option<Visualization>,
+    /// The steps behind the answer, for the days where they're worth reading
+    pub explanation: Option<Explanation>,
+}
+
+impl Day {
+    /**
+     * Build this day's solution using the given parameter values.
+     */
+    pub fn solution(&self, params: &Params) -> Box<dyn DynSolution> {
+        (self.solution)(params)
+    }
+
+    pub fn has_param(&self, name: &str) -> bool {
+        self.params.iter().any(|param| param.name == name)
+    }
+
+    /**
+     * Build this day's parameters from their defaults, replacing any that appear in `overrides`.
+     * Overrides for parameters this day doesn't declare are ignored.
+     */
+    pub fn params(&self, overrides: &[(String, i64)]) -> Params {
+        let mut values = self
+            .params
+            .iter()
+            .map(|param| (param.name, param.default))
+            .collect::<HashMap<_, _>>();
+        for (name, value) in overrides {
+            if let Some(entry) = values.get_mut(name.as_str()) {
+                *entry = *value;
+            }
+        }
+        Params { values }
+    }
+}
+
+/**
+ * An extra numeric argument a day's solution needs besides its input, like the row to scan in day 15.
+ */
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+}
+
+/**
+ * Runs one of a day's simulations on an input, sending its frames to a visualizer.
+ */
+pub struct Visualization {
+    pub parts: &'static [u8],
+    pub run: fn(&str, u8, &mut dyn Visualizer) -> Result<(), ParseError>,
+}
+
+/**
+ * Solves one part of a day on an input, sending the steps behind the answer to a tracer and
+ * returning the answer.
+ */
+pub struct Explanation {
+    pub parts: &'static [u8],
+    pub run: fn(&str, u8, &mut dyn Tracer) -> Result<String, ParseError>,
+}
+
+/**
+ * Parameter values for a single run, starting from each parameter's default.
+ */
+#[derive(Debug, Clone)]
+pub struct Params {
+    values: HashMap<&'static str, i64>,
+}
+
+impl Params {
+    pub fn get(&self, name: &str) -> i64 {
+        *self
+            .values
+            .get(name)
+            .unwrap_or_else(|| panic!("Unknown parameter: {}", name))
+    }
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day {
+            day: 1,
+            input: include_str!("day01/inputs/input.txt"),
+            records: Records::Blocks,
+            params: &[],
+            solution: |_| Box::new(day01::Day01),
+            generate: day01::generate::input,
+            visualization: None,
+            explanation: None,
+        },
+        Day {
+            day: 2,
+            input: include_str!("day02/inputs/input.txt"),
+            records: Records::Lines,
+            params: &[],
+            solution: |_| Box::new(day02::Day02),
+            generate: day02::generate::input,
+            visualization: None,
+            explanation: None,
+        },
+        Day {
+            day: 3,
+            input: include_str!("day03/inputs/input.txt"),
+            records: Records::Lines,
+            params: &[],
+            solution: |_| Box::new(day03::Day03),
+            generate: day03::generate::input,
+            visualization: None,
+            explanation: None,
+        },
+        Day {
+            day: 4,
+            input: include_str!("day04/inputs/input.txt"),
+            records: Records::Lines,
+            params: &[],
+            solution: |_| Box::new(day04::Day04),
+            generate: day04::generate::input,
+            visualization: None,
+            explanation: None,
+        },
+        Day {
+            day: 5,
+            input: include_str!("day05/inputs/input.txt"),
+            records: Records::Whole,
+            params: &[],
+            solution: |_| Box::new(day05::Day05),
+            generate: day05::generate::input,
+            visualization: None,
+            explanation: None,
+        },
+        Day {
+            day: 6,
+            input: include_str!("day06/inputs/input.txt"),
+            records: Records::Whole,
+            params: &[],
+            solution: |_| Box::new(day06::Day06),
+            generate: day06::generate::input,
+            visualization: None,
+            explanation: None,
+        },
+        Day {
+            day: 7,
+            input: include_str!("day07/inputs/input.txt"),
+            records: Records::Whole,
+            params: &[],
+            solution: |_| Box::new(day07::Day07),
+            generate: day07::generate::input,
+            visualization: None,
+            explanation: Some(Explanation {
+                parts: &[1, 2],
+                run: day07::explain,
+            }),
+        },
+        Day {
+            day: 8,
+            input: include_str!("day08/inputs/input.txt"),
+            records: Records::Whole,
+            params: &[],
+            solution: |_| Box::new(day08::Day08),
+            generate: day08::generate::input,
+            visualization: None,
+            explanation: None,
+        },
+        Day {
+            day: 9,
+            input: include_str!("day09/inputs/input.txt"),
+            records: Records::Lines,
+            params: &[],
+            solution: |_| Box::new(day09::Day09),
+            generate: day09::generate::input,
+            visualization: Some(Visualization {
+                parts: &[1, 2],
+                run: day09::visualize,
+            }),
+            explanation: None,
+        },
+        Day {
+            day: 10,
+            input: include_str!("day10/inputs/input.txt"),
+            records: Records::Lines,
+            params: &[],
+            solution: |_| Box::new(day10::Day10),
+            generate: day10::generate::input,
+            visualization: None,
+            explanation: None,
+        },
+        Day {
+            day: 11,
+            input: include_str!("day11/inputs/input.txt"),
+            records: Records::Blocks,
+            params: &[],
+            solution: |_| Box::new(day11::Day11),
+            generate: day11::generate::input,
+            visualization: None,
+            explanation: None,
+        },
+        Day {
+            day: 12,
+            input: include_str!("day12/inputs/input.txt"),
+            records: Records::Whole,
+            params: &[],
+            solution: |_| Box::new(day12::Day12),
+            generate: day12::generate::input,
+            visualization: None,
+            explanation: Some(Explanation {
+                parts: &[1, 2],
+                run: day12::explain,
+            }),
+        },
+        Day {
+            day: 13,
+            input: include_str!("day13/inputs/input.txt"),
+            records: Records::Blocks,
+            params: &[],
+            solution: |_| Box::new(day13::Day13),
+            generate: day13::generate::input,
+            visualization: None,
+            explanation: Some(Explanation {
+                parts: &[1],
+                run: day13::explain,
+            }),
+        },
+        Day {
+            day: 14,
+            input: include_str!("day14/inputs/input.txt"),
+            records: Records::Lines,
+            params: &[],
+            solution: |_| Box::new(day14::Day14),
+            generate: day14::generate::input,
+            visualization: Some(Visualization {
+                parts: &[1, 2],
+                run: day14::visualize,
+            }),
+            explanation: None,
+        },
+        Day {
+            day: 15,
+            input: include_str!("day15/inputs/input.txt"),
+            records: Records::Lines,
+            params: &[
+                Param {
+                    name: "row",
+                    default: 2000000,
+                },
+                Param {
+                    name: "max_x",
+                    default: 4000000,
+                },
+                Param {
+                    name: "max_y",
+                    default: 4000000,
+                },
+            ],
+            solution: |params| {
+                Box::new(day15::Day15 {
+                    row: params.get("row"),
+                    max_x: params.get("max_x"),
+                    max_y: params.get("max_y"),
+                })
+            },
+            generate: day15::generate::input,
+            visualization: None,
+            explanation: None,
+        },
+        Day {
+            day: 16,
+            input: include_str!("day16/inputs/input.txt"),
+            records: Records::Whole,
+            params: &[],
+            solution: |_| Box::new(day16::Day16),
+            generate: day16::generate::input,
+            visualization: None,
+            explanation: Some(Explanation {
+                parts: &[1, 2],
+                run: day16::explain,
+            }),
+        },
+        Day {
+            day: 17,
+            input: include_str!("day17/inputs/input.txt"),
+            records: Records::Whole,
+            params: &[],
+            solution: |_| Box::new(day17::Day17),
+            generate: day17::generate::input,
+            visualization: Some(Visualization {
+                parts: &[1],
+                run: day17::visualize,
+            }),
+            explanation: None,
+        },
+        Day {
+            day: 18,
+            input: include_str!("day18/inputs/input.txt"),
+            records: Records::Lines,
+            params: &[],
+            solution: |_| Box::new(day18::Day18),
+            generate: day18::generate::input,
+            visualization: None,
+            explanation: None,
+        },
+        Day {
+            day: 19,
+            input: include_str!("day19/inputs/input.txt"),
+            records: Records::Lines,
+            params: &[],
+            solution: |_| Box::new(day19::Day19),
+            generate: day19::generate::input,
+            visualization: None,
+            explanation: Some(Explanation {
+                parts: &[1, 2],
+                run: day19::explain,
+            }),
+        },
+        Day {
+            day: 20,
+            input: include_str!("day20/inputs/input.txt"),
+            records: Records::Lines,
+            params: &[],
+            solution: |_| Box::new(day20::Day20),
+            generate: day20::generate::input,
+            visualization: None,
+            explanation: None,
+        },
+        Day {
+            day: 21,
+            input: include_str!("day21/inputs/input.txt"),
+            records: Records::Lines,
+            params: &[],
+            solution: |_| Box::new(day21::Day21),
+            generate: day21::generate::input,
+            visualization: None,
+            explanation: None,
+        },
+        Day {
+            day: 22,
+            input: include_str!("day22/inputs/input.txt"),
+            records: Records::Whole,
+            params: &[],
+            solution: |_| Box::new(day22::Day22),
+            generate: day22::generate::input,
+            visualization: Some(Visualization {
+                parts: &[1],
+                run: day22::visualize,
+            }),
+            explanation: None,
+        },
+    ]
+}
+
+pub fn get(day: u8) -> Option<Day> {
+    all().into_iter().find(|d| d.day == day)
+}
@@ -0,0 +1,209 @@
+use serde_json::{json, Value};
+
+use super::batch::{DayResult, Outcome, Row};
+
+/**
+ * How the runner prints its results.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Format> {
+        match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+/**
+ * The columns of a CSV report, which are also the keys of each JSON result.
+ */
+pub const COLUMNS: [&str; 8] = [
+    "day",
+    "part",
+    "status",
+    "answer",
+    "answer_type",
+    "duration_ns",
+    "input_hash",
+    "error",
+];
+
+/**
+ * What kind of value an answer is: `integer`, `multiline` (like day 10's CRT image) or `string`.
+ */
+pub fn answer_type(answer: &str) -> &'static str {
+    let digits = answer.strip_prefix('-').unwrap_or(answer);
+    if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        "integer"
+    } else if answer.contains('\n') {
+        "multiline"
+    } else {
+        "string"
+    }
+}
+
+/**
+ * A 64-bit FNV-1a hash of the input, as hex. It's stable across builds and platforms, so it can
+ * be used to tell which input an answer came from.
+ */
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/**
+ * The values for one row of a report, in the order of `COLUMNS`.
+ */
+fn record(result: &DayResult, row: &Row) -> [Value; 8] {
+    let (status, answer, error) = match &row.outcome {
+        Outcome::Answer(answer) => ("ok", Some(answer.as_str()), None),
+        Outcome::NotImplemented => ("not_implemented", None, None),
+        Outcome::Error(e) => ("error", None, Some(e.as_str())),
+    };
+    [
+        json!(result.day),
+        json!(row.part),
+        json!(status),
+        json!(answer),
+        json!(answer.map(answer_type)),
+        json!(row.time.as_nanos() as u64),
+        json!(result.input_hash),
+        json!(error),
+    ]
+}
+
+fn records(results: &[DayResult]) -> impl Iterator<Item = [Value; 8]> + '_ {
+    results
+        .iter()
+        .flat_map(|result| result.rows.iter().map(move |row| record(result, row)))
+}
+
+/**
+ * A JSON report: `{"version": 1, "results": [...]}`, with one object per row keyed by `COLUMNS`.
+ * Missing values are null.
+ */
+pub fn to_json(results: &[DayResult]) -> Value {
+    let results = records(results)
+        .map(|values| {
+            COLUMNS
+                .iter()
+                .map(|column| column.to_string())
+                .zip(values)
+                .collect::<serde_json::Map<_, _>>()
+        })
+        .collect::<Vec<_>>();
+    json!({ "version": 1, "results": results })
+}
+
+/**
+ * A CSV report with a header row of `COLUMNS`. Missing values are empty, and fields with commas,
+ * quotes or line breaks (like multi-line answers) are quoted.
+ */
+pub fn to_csv(results: &[DayResult]) -> String {
+    let mut csv = COLUMNS.join(",") + "\n";
+    for values in records(results) {
+        let fields = values
+            .iter()
+            .map(|value| match value {
+                Value::Null => String::new(),
+                Value::String(text) => csv_field(text),
+                other => other.to_string(),
+            })
+            .collect::<Vec<_>>();
+        csv += &fields.join(",");
+        csv += "\n";
+    }
+    csv
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn results() -> Vec<DayResult> {
+        let row = |part, outcome| Row {
+            part,
+            outcome,
+            time: Duration::from_nanos(1500),
+        };
+        vec![
+            DayResult {
+                day: 10,
+                rows: vec![
+                    row(Some(1), Outcome::Answer("13140".to_string())),
+                    row(Some(2), Outcome::Answer("##..\n#..#".to_string())),
+                ],
+                input_hash: Some(input_hash("noop")),
+                elapsed: Duration::from_micros(3),
+            },
+            DayResult {
+                day: 22,
+                rows: vec![row(None, Outcome::Error("bad input, sorry".to_string()))],
+                input_hash: None,
+                elapsed: Duration::from_micros(1),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_answer_type() {
+        assert_eq!(answer_type("-42"), "integer");
+        assert_eq!(answer_type("CMZ"), "string");
+        assert_eq!(answer_type("-"), "string");
+        assert_eq!(answer_type("##\n.."), "multiline");
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn test_json() {
+        let json = to_json(&results());
+        assert_eq!(json["version"], 1);
+        let row = &json["results"][1];
+        assert_eq!(row["part"], 2);
+        assert_eq!(row["answer"], "##..\n#..#");
+        assert_eq!(row["answer_type"], "multiline");
+        assert_eq!(row["duration_ns"], 1500);
+        assert_eq!(json["results"][2]["status"], "error");
+        assert_eq!(json["results"][2]["part"], Value::Null);
+    }
+
+    #[test]
+    fn test_csv() {
+        let hash = input_hash("noop");
+        assert_eq!(
+            to_csv(&results()),
+            format!(
+                "day,part,status,answer,answer_type,duration_ns,input_hash,error\n\
+                 10,1,ok,13140,integer,1500,{hash},\n\
+                 10,2,ok,\"##..\n#..#\",multiline,1500,{hash},\n\
+                 22,,error,,,1500,,\"bad input, sorry\"\n"
+            )
+        );
+    }
+}
@@ -0,0 +1,460 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/**
+ * A number that can be used as a point's coordinate.
+ */
+pub trait Coordinate:
+    Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn to_f64(self) -> f64;
+
+    /**
+     * The distance between two values, which never underflows for unsigned types.
+     */
+    fn distance(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    /**
+     * 1 if the value is positive, -1 if it's negative, 0 otherwise.
+     */
+    fn signum(self) -> Self {
+        if self > Self::ZERO {
+            Self::ONE
+        } else if self < Self::ZERO {
+            Self::ZERO - Self::ONE
+        } else {
+            Self::ZERO
+        }
+    }
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: $t = 0 as $t;
+                const ONE: $t = 1 as $t;
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+fn max<T: Coordinate>(a: T, b: T) -> T {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/**
+ * A point on a 2D plane. Directions treat `y` as increasing downwards, like rows on a screen.
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point2<T> {
+    pub fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2<T>) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /**
+     * The number of king's moves between two points: the largest distance along any axis.
+     */
+    pub fn chebyshev(self, other: Point2<T>) -> T {
+        max(self.x.distance(other.x), self.y.distance(other.y))
+    }
+
+    pub fn euclidean(self, other: Point2<T>) -> f64 {
+        let (dx, dy) = (
+            self.x.distance(other.x).to_f64(),
+            self.y.distance(other.y).to_f64(),
+        );
+        (dx * dx + dy * dy).sqrt()
+    }
+
+    /**
+     * The sign of each coordinate, e.g. the single step that moves most directly towards `self`
+     * when `self` is a difference between two points.
+     */
+    pub fn signum(self) -> Point2<T> {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl<T: Coordinate + Neg<Output = T>> Point2<T> {
+    /**
+     * The point one step away in `direction`.
+     */
+    pub fn step(self, direction: Direction) -> Point2<T> {
+        self + direction.offset()
+    }
+
+    /**
+     * The points above, right of, below and left of this one.
+     */
+    pub fn neighbors4(self) -> impl Iterator<Item = Point2<T>> {
+        Direction::ALL.into_iter().map(move |d| self + d.offset())
+    }
+
+    /**
+     * Like `neighbors4`, but including the diagonals.
+     */
+    pub fn neighbors8(self) -> impl Iterator<Item = Point2<T>> {
+        Direction8::ALL.into_iter().map(move |d| self + d.offset())
+    }
+}
+
+impl<T: Coordinate> Add for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn mul(self, scale: T) -> Point2<T> {
+        Point2::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl<T: Coordinate + Neg<Output = T>> Neg for Point2<T> {
+    type Output = Point2<T>;
+
+    fn neg(self) -> Point2<T> {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Point2<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Point2<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> (T, T) {
+        (point.x, point.y)
+    }
+}
+
+/**
+ * A point in 3D space.
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3<T>) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+
+    pub fn chebyshev(self, other: Point3<T>) -> T {
+        max(
+            max(self.x.distance(other.x), self.y.distance(other.y)),
+            self.z.distance(other.z),
+        )
+    }
+
+    pub fn euclidean(self, other: Point3<T>) -> f64 {
+        let (dx, dy, dz) = (
+            self.x.distance(other.x).to_f64(),
+            self.y.distance(other.y).to_f64(),
+            self.z.distance(other.z).to_f64(),
+        );
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+
+    pub fn signum(self) -> Point3<T> {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+}
+
+impl<T: Coordinate + Neg<Output = T>> Point3<T> {
+    /**
+     * The six points that share a face with this one.
+     */
+    pub fn neighbors6(self) -> impl Iterator<Item = Point3<T>> {
+        let (one, zero) = (T::ONE, T::ZERO);
+        [
+            Point3::new(one, zero, zero),
+            Point3::new(-one, zero, zero),
+            Point3::new(zero, one, zero),
+            Point3::new(zero, -one, zero),
+            Point3::new(zero, zero, one),
+            Point3::new(zero, zero, -one),
+        ]
+        .into_iter()
+        .map(move |offset| self + offset)
+    }
+
+    /**
+     * The 26 points that share a face, edge or corner with this one.
+     */
+    pub fn neighbors26(self) -> impl Iterator<Item = Point3<T>> {
+        let steps = [-T::ONE, T::ZERO, T::ONE];
+        steps
+            .into_iter()
+            .flat_map(move |x| {
+                steps
+                    .into_iter()
+                    .flat_map(move |y| steps.into_iter().map(move |z| Point3::new(x, y, z)))
+            })
+            .filter(|offset| *offset != Point3::new(T::ZERO, T::ZERO, T::ZERO))
+            .map(move |offset| self + offset)
+    }
+}
+
+impl<T: Coordinate> Add for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Coordinate> Sub for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Point3<T> {
+    type Output = Point3<T>;
+
+    fn mul(self, scale: T) -> Point3<T> {
+        Point3::new(self.x * scale, self.y * scale, self.z * scale)
+    }
+}
+
+impl<T: Coordinate + Neg<Output = T>> Neg for Point3<T> {
+    type Output = Point3<T>;
+
+    fn neg(self) -> Point3<T> {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point3<T> {
+    fn add_assign(&mut self, other: Point3<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, other: Point3<T>) {
+        *self = *self - other;
+    }
+}
+
+/**
+ * One of the four directions along the axes, in clockwise order.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /**
+     * The change in position from one step in this direction.
+     */
+    pub fn offset<T: Coordinate + Neg<Output = T>>(self) -> Point2<T> {
+        let (one, zero) = (T::ONE, T::ZERO);
+        match self {
+            Direction::Up => Point2::new(zero, -one),
+            Direction::Right => Point2::new(one, zero),
+            Direction::Down => Point2::new(zero, one),
+            Direction::Left => Point2::new(-one, zero),
+        }
+    }
+}
+
+/**
+ * One of the eight directions along the axes and diagonals, in clockwise order.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub fn offset<T: Coordinate + Neg<Output = T>>(self) -> Point2<T> {
+        let (one, zero) = (T::ONE, T::ZERO);
+        let (x, y) = match self {
+            Direction8::Up => (zero, -one),
+            Direction8::UpRight => (one, -one),
+            Direction8::Right => (one, zero),
+            Direction8::DownRight => (one, one),
+            Direction8::Down => (zero, one),
+            Direction8::DownLeft => (-one, one),
+            Direction8::Left => (-one, zero),
+            Direction8::UpLeft => (-one, -one),
+        };
+        Point2::new(x, y)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Direction8 {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(1, 5);
+        let b = Point2::new(4, 1);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.euclidean(b), 5.0);
+
+        let a = Point2::new(3usize, 0);
+        assert_eq!(a.manhattan(Point2::new(0, 2)), 5);
+
+        let a = Point3::new(1, 1, 1);
+        assert_eq!(a.manhattan(Point3::new(-1, 2, 4)), 6);
+        assert_eq!(a.chebyshev(Point3::new(-1, 2, 4)), 3);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut a = Point2::new(2, -3);
+        a += Point2::new(1, 1);
+        assert_eq!(a, Point2::new(3, -2));
+        assert_eq!(a * 2, Point2::new(6, -4));
+        assert_eq!(-a, Point2::new(-3, 2));
+        assert_eq!((a - Point2::new(0, 5)).signum(), Point2::new(1, -1));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let origin = Point2::new(0, 0);
+        assert_eq!(
+            origin.neighbors4().collect::<Vec<_>>(),
+            [
+                Point2::new(0, -1),
+                Point2::new(1, 0),
+                Point2::new(0, 1),
+                Point2::new(-1, 0),
+            ]
+        );
+        assert!(origin.neighbors8().all(|p| p.chebyshev(origin) == 1));
+        assert_eq!(origin.neighbors8().count(), 8);
+
+        let origin = Point3::new(0, 0, 0);
+        assert!(origin.neighbors6().all(|p| p.manhattan(origin) == 1));
+        assert_eq!(origin.neighbors26().count(), 26);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Right.reverse(), Direction::Left);
+        assert_eq!(Point2::new(2, 2).step(Direction::Up), Point2::new(2, 1));
+    }
+}
@@ -0,0 +1,261 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/**
+ * A cell's position in a grid, as (x, y), with (0, 0) at the top left.
+ */
+pub type Position = (usize, usize);
+
+/**
+ * A rectangular grid of cells, stored row by row.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /**
+     * A `width` by `height` grid with every cell set to `fill`.
+     */
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /**
+     * Build a grid from a character map, one row per line, converting each character with `cell`.
+     *
+     * Lines shorter than the longest one are padded with spaces, which are passed to `cell` like
+     * any other character. Stops at the first error `cell` returns.
+     */
+    pub fn parse<'a, E>(
+        lines: impl IntoIterator<Item = &'a str>,
+        mut cell: impl FnMut(Position, char) -> Result<T, E>,
+    ) -> Result<Grid<T>, E> {
+        let lines = lines.into_iter().collect::<Vec<_>>();
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let mut chars = line.chars();
+            for x in 0..width {
+                cells.push(cell((x, y), chars.next().unwrap_or(' '))?);
+            }
+        }
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /**
+     * The cell at `position`, or None if it's outside the grid.
+     */
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /**
+     * The cells in column `x`, from top to bottom.
+     */
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /**
+     * Every position in the grid, row by row.
+     */
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /**
+     * Every cell along with its position, row by row.
+     */
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /**
+     * The positions directly above, right of, below and left of `position` that are inside the
+     * grid.
+     */
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offsets(position, &[(0, -1), (1, 0), (0, 1), (-1, 0)])
+    }
+
+    /**
+     * Like `neighbors4`, but including the diagonals.
+     */
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offsets(
+            position,
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+            ],
+        )
+    }
+
+    /**
+     * Add a row to the bottom of the grid. The row must be as wide as the grid.
+     */
+    pub fn push_row(&mut self, row: Vec<T>) {
+        assert_eq!(row.len(), self.width, "row is the wrong width for the grid");
+        self.cells.extend(row);
+        self.height += 1;
+    }
+
+    /**
+     * Flip the grid over its diagonal, so rows become columns.
+     */
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|x| self.column(x).cloned())
+                .collect(),
+        }
+    }
+
+    /**
+     * Draw the grid one line per row, converting each cell with `cell`.
+     */
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.1 * self.width + position.0)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        (x, y): Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let neighbor = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(neighbor).then_some(neighbor)
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|c| *c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("ab\ncde\nf".lines(), |_, c| Ok::<_, ()>(c)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.row(0), ['a', 'b', ' ']);
+        assert_eq!(grid.column(1).collect::<String>(), "bd ");
+        assert_eq!(grid[(2, 1)], 'e');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "ab \ncde\nf  ");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = sample();
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbors8((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (1, 1), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let grid = Grid::parse(["abc", "def"], |_, c| Ok::<_, ()>(c)).unwrap();
+        let transposed = grid.transpose();
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(transposed.to_string(), "ad\nbe\ncf");
+        assert_eq!(transposed.transpose(), grid);
+    }
+}
@@ -0,0 +1,91 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use super::days::Day;
+
+/**
+ * Environment variable pointing at a directory of personal inputs, used when no input is passed on
+ * the command line.
+ */
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/**
+ * Where to read a day's puzzle input from.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The `inputs/input.txt` file compiled into the binary
+    Bundled,
+    /// A single file, used as-is for the selected day
+    File(PathBuf),
+    /// Standard input
+    Stdin,
+    /// A directory holding one file per day, named like `day15.txt`
+    Directory(PathBuf),
+}
+
+impl InputSource {
+    /**
+     * Use the input directory from the environment, if one is set; otherwise use the bundled inputs.
+     */
+    pub fn from_env() -> InputSource {
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(dir) if !dir.is_empty() => InputSource::Directory(PathBuf::from(dir)),
+            _ => InputSource::Bundled,
+        }
+    }
+
+    /**
+     * Load the input for `day`. Trailing newlines are trimmed, since the solutions expect input
+     * shaped like the bundled files, which have none.
+     */
+    pub fn load(&self, day: &Day) -> io::Result<String> {
+        let input = match self {
+            InputSource::Bundled => return Ok(day.input.to_string()),
+            InputSource::File(path) => fs::read_to_string(path)?,
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                input
+            }
+            InputSource::Directory(dir) => {
+                let path = dir.join(format!("day{:02}.txt", day.day));
+                fs::read_to_string(&path)
+                    .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?
+            }
+        };
+        Ok(input.trim_end_matches(['\n', '\r']).to_string())
+    }
+
+    /**
+     * Where the expected answers for `day`'s input live: `inputs/answers.txt` next to a bundled
+     * input, or `dayNN.answers.txt` in an input directory. Single files and stdin have none.
+     */
+    pub fn answers_path(&self, day: &Day) -> Option<PathBuf> {
+        match self {
+            InputSource::Bundled => Some(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join(format!("src/day{:02}/inputs/answers.txt", day.day)),
+            ),
+            InputSource::Directory(dir) => Some(dir.join(format!("day{:02}.answers.txt", day.day))),
+            InputSource::File(_) | InputSource::Stdin => None,
+        }
+    }
+
+    /**
+     * Where `day`'s corpus of extra inputs lives: `inputs/corpus/` next to a bundled input, or
+     * `corpus/dayNN/` in an input directory. Single files and stdin have none.
+     */
+    pub fn corpus_dir(&self, day: &Day) -> Option<PathBuf> {
+        match self {
+            InputSource::Bundled => Some(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join(format!("src/day{:02}/inputs/corpus", day.day)),
+            ),
+            InputSource::Directory(dir) => Some(dir.join(format!("corpus/day{:02}", day.day))),
+            InputSource::File(_) | InputSource::Stdin => None,
+        }
+    }
+}
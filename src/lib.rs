@@ -0,0 +1,51 @@
+/*!
+ * Advent of Code 2022 solutions.
+ *
+ * Each `dayNN` module exposes its parsers, models and per-part solvers, along with a type
+ * implementing [`solution::Solution`]. The `aoc` runner and the per-day binaries are thin wrappers
+ * over this crate.
+ */
+
+pub mod answers;
+pub mod arith;
+pub mod batch;
+pub mod bench;
+pub mod corpus;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod days;
+pub mod format;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod profile;
+pub mod property;
+pub mod random;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod stream;
+pub mod trace;
+pub mod validate;
+pub mod visualize;
@@ -1,3 +1,1045 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::slice;
+use std::thread;
+use std::time::{Instant, SystemTime};
+
+use aoc::answers::Answers;
+use aoc::batch::{self, DayResult, Outcome};
+use aoc::bench::{self, Baseline, Short};
+use aoc::corpus::{self, Status};
+use aoc::days::{self, Day};
+use aoc::format::{self, Format};
+use aoc::input::InputSource;
+use aoc::profile::{self, Bytes};
+use aoc::random::Rng;
+use aoc::scaffold;
+use aoc::trace::Step;
+use aoc::validate::{self, Records};
+use aoc::visualize::{FrameDump, Player};
+
+const USAGE: &str = "Usage:
+    aoc list
+    aoc run <day> [--part <1|2>] [--input <file|->] [--param <name>=<value>]...
+    aoc run --all [--part <1|2>] [--input-dir <dir>] [--param <name>=<value>]...
+                  [--parallel] [--jobs <n>]
+    aoc run <day|--all> [run options] --format <text|json|csv>
+    aoc run <day|--all> [run options] [--explain] [--explain-json <file>]
+    aoc bench <day|--all> [run options] [--iterations <n>] [--baseline <file>] [--save]
+              [--threshold <percent>]
+    aoc profile <day|--all> [--part <1|2>] [--input <file|->] [--input-dir <dir>]
+                [--param <name>=<value>]...
+    aoc verify [day|--all] [--part <1|2>] [--input-dir <dir>] [--param <name>=<value>]...
+               [--record]
+    aoc visualize <day> [--part <1|2>] [--input <file>] [--param <name>=<value>]...
+                  [--fps <n>] [--dump <dir>]
+    aoc corpus [day|--all] [--part <1|2>] [--input-dir <dir>]
+    aoc gen <day> [--size <n>] [--seed <n>]
+    aoc check-input <day> <file|->
+    aoc new <day>
+
+Inputs default to the bundled inputs/input.txt, or to <dir>/dayNN.txt when
+AOC_INPUT_DIR is set. Pass `--input -` to read from stdin.
+
+`run --parallel` runs the days at the same time on a pool of threads (one per
+CPU, or --jobs) and prints a summary table of answers and times once they've
+all finished. Panics and errors are reported in the table.
+
+`--format json` or `--format csv` prints one record per part instead, with
+the day, part, status, answer, answer type, duration in nanoseconds, input
+hash and any error. Multi-line answers are escaped (JSON) or quoted (CSV).
+
+`--explain` prints the steps behind each answer under it, like the
+directories day 7 adds up or the path day 12 takes (days 7, 12, 13, 16 and
+19). `--explain-json <file>` writes them to a JSON file instead, one record per
+part with the day, part, answer and steps. Neither works with --parallel or
+--format.
+
+`bench` times parsing and each part, and compares medians against the baseline
+file (default bench-baseline.json) if it exists. `--save` writes the results
+into the baseline.
+
+`profile` reports how many allocations parsing and each part make, how many
+bytes they allocate in total, and the most memory they hold at once. It needs
+the counting allocator: build with `--features alloc-profile`.
+
+`verify` checks every day (or the given one) against the answers recorded in
+inputs/answers.txt, or <dir>/dayNN.answers.txt for an input directory.
+`--record` saves the current answers instead.
+
+`corpus` runs every input in each day's inputs/corpus/ directory (or
+<dir>/corpus/dayNN/ for an input directory) and checks it against the answers
+in the matching <name>.answers.txt, reporting each part's result and time.
+
+`visualize` plays a day's simulation in the terminal (days 9, 14, 17 and 22).
+Type a command and press Enter: Enter alone steps one frame, p plays or
+pauses, + and - change the speed, and q skips to the end. `--dump <dir>`
+writes each frame to its own file instead.
+
+`gen` prints a random input for a day. `--size` scales it (default 10; what it
+counts depends on the day, like elves, lines or grid width), and the same
+seed always gives the same input. Without --seed, a random seed is used and
+printed to stderr.
+
+`check-input` checks a file against a day's input format without solving it,
+reporting every malformed line or block it finds (or the first problem, for
+inputs like maps that only parse whole) and the file's line, block and integer
+counts and ranges. It exits with status 1 if there are problems.
+
+`new` adds a day to the source tree this runner was built from: a src/dayNN
+module whose parts return Unsolved, a test stub, empty inputs, and its entries
+in src/lib.rs, src/days.rs and Cargo.toml. Existing days are never touched.";
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+/**
+ * Print the answer for one part, putting multi-line answers (like day 10's CRT image) on their own lines.
+ */
+fn print_answer(part: u8, answer: &str) {
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer);
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
+
+fn load_input(day: &Day, source: &InputSource) -> Option<String> {
+    match source.load(day) {
+        Ok(input) => Some(input),
+        Err(e) => {
+            eprintln!("Could not read input for day {}: {}", day.day, e);
+            None
+        }
+    }
+}
+
+/**
+ * What `run --explain` and `--explain-json` asked for, and the steps collected for the JSON file.
+ */
+#[derive(Default)]
+struct Explain {
+    print: bool,
+    json: Option<PathBuf>,
+    records: Vec<serde_json::Value>,
+}
+
+impl Explain {
+    fn enabled(&self) -> bool {
+        self.print || self.json.is_some()
+    }
+}
+
+/**
+ * Run the selected parts of one day, explaining the answers if asked and the day can. Returns false
+ * if its input couldn't be read or parsed.
+ */
+fn run_day(
+    day: &Day,
+    parts: &[u8],
+    source: &InputSource,
+    params: &[(String, i64)],
+    explain: &mut Explain,
+) -> bool {
+    println!("Day {:02}", day.day);
+    let input = match load_input(day, source) {
+        Some(input) => input,
+        None => return false,
+    };
+    let solution = day.solution(&day.params(params));
+    // panics are reported like `run --parallel` reports them, instead of ending the run
+    let parsed = batch::quietly(|| batch::catch(|| solution.parse(&input)))
+        .and_then(|parsed| parsed.map_err(|e| e.to_string()));
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Could not parse input: {}", e);
+            return false;
+        }
+    };
+    let mut ok = true;
+    for &part in parts {
+        let explanation = day
+            .explanation
+            .as_ref()
+            .filter(|explanation| explain.enabled() && explanation.parts.contains(&part));
+        let Some(explanation) = explanation else {
+            match batch::quietly(|| batch::catch(|| solution.part(part, parsed.as_ref()))) {
+                Ok(Some(answer)) => print_answer(part, &answer),
+                Ok(None) => println!("Part {}: not implemented", part),
+                Err(e) => {
+                    println!("Part {}: ERROR: {}", part, e);
+                    ok = false;
+                    continue;
+                }
+            }
+            if explain.enabled() && solution.has_part(part) {
+                println!("  (no explanation for this part)");
+            }
+            continue;
+        };
+        let mut steps: Vec<Step> = vec![];
+        let answer =
+            match batch::quietly(|| batch::catch(|| (explanation.run)(&input, part, &mut steps))) {
+                Ok(Ok(answer)) => answer,
+                Ok(Err(e)) => {
+                    eprintln!("Could not parse input: {}", e);
+                    return false;
+                }
+                Err(e) => {
+                    println!("Part {}: ERROR: {}", part, e);
+                    ok = false;
+                    continue;
+                }
+            };
+        print_answer(part, &answer);
+        if explain.print {
+            for step in &steps {
+                println!("  {}", step);
+            }
+        }
+        explain.records.push(serde_json::json!({
+            "day": day.day,
+            "part": part,
+            "answer": answer,
+            "steps": steps.iter().map(Step::to_json).collect::<Vec<_>>(),
+        }));
+    }
+    ok
+}
+
+fn list() {
+    for day in days::all() {
+        let solution = day.solution(&day.params(&[]));
+        let parts = [1, 2]
+            .iter()
+            .filter(|&&part| solution.has_part(part))
+            .map(|part| part.to_string())
+            .collect::<Vec<_>>();
+        let params = day
+            .params
+            .iter()
+            .map(|param| format!("{}={}", param.name, param.default))
+            .collect::<Vec<_>>();
+        if parts.is_empty() {
+            println!("Day {:02}: not solved yet", day.day);
+        } else if params.is_empty() {
+            println!("Day {:02}: part {}", day.day, parts.join(", "));
+        } else {
+            println!(
+                "Day {:02}: part {} ({})",
+                day.day,
+                parts.join(", "),
+                params.join(", ")
+            );
+        }
+    }
+}
+
+/**
+ * The days, parts, input and parameters picked on the command line, shared by `run` and `bench`.
+ */
+struct Selection {
+    days: Vec<Day>,
+    parts: Vec<u8>,
+    source: InputSource,
+    params: Vec<(String, i64)>,
+}
+
+/**
+ * Parse the day selection options; with `default_all`, leaving out the day selects every day. Any
+ * other flag is offered to `extra` along with the remaining arguments; it returns false if it
+ * doesn't recognize the flag either.
+ */
+fn parse_selection(
+    args: &[String],
+    default_all: bool,
+    mut extra: impl FnMut(&str, &mut slice::Iter<String>) -> bool,
+) -> Selection {
+    let mut day = None;
+    let mut all = false;
+    let mut parts = vec![1, 2];
+    let mut source = None;
+    let mut params = vec![];
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--part" | "-p" => {
+                let part = args
+                    .next()
+                    .and_then(|p| p.parse::<u8>().ok())
+                    .filter(|p| *p == 1 || *p == 2)
+                    .unwrap_or_else(|| usage_error("--part expects 1 or 2"));
+                parts = vec![part];
+            }
+            "--input" | "-i" => {
+                let path = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("--input expects a file path or -"));
+                source = Some(if path == "-" {
+                    InputSource::Stdin
+                } else {
+                    InputSource::File(PathBuf::from(path))
+                });
+            }
+            "--input-dir" => {
+                let dir = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("--input-dir expects a directory"));
+                source = Some(InputSource::Directory(PathBuf::from(dir)));
+            }
+            "--param" => {
+                let (name, value) = args
+                    .next()
+                    .and_then(|p| p.split_once('='))
+                    .and_then(|(name, value)| Some((name, value.parse::<i64>().ok()?)))
+                    .unwrap_or_else(|| usage_error("--param expects <name>=<integer>"));
+                params.push((name.to_string(), value));
+            }
+            flag if extra(flag, &mut args) => {}
+            _ => match arg.parse::<u8>() {
+                Ok(d) if day.is_none() => day = Some(d),
+                _ => usage_error(&format!("Unexpected argument: {}", arg)),
+            },
+        }
+    }
+
+    let selected = match (day, all) {
+        (Some(_), true) => usage_error("Pass either a day or --all, not both"),
+        (None, false) if !default_all => usage_error("Missing day"),
+        (None, _) => days::all(),
+        (Some(d), false) => match days::get(d) {
+            Some(day) => vec![day],
+            None => usage_error(&format!("No solution for day {}", d)),
+        },
+    };
+
+    let source = source.unwrap_or_else(InputSource::from_env);
+    if selected.len() > 1 {
+        if let InputSource::File(_) | InputSource::Stdin = source {
+            usage_error("--input only applies to a single day; use --input-dir with --all");
+        }
+    }
+    for (name, _) in &params {
+        if !selected.iter().any(|day| day.has_param(name)) {
+            usage_error(&format!("Unknown parameter: {}", name));
+        }
+    }
+
+    Selection {
+        days: selected,
+        parts,
+        source,
+        params,
+    }
+}
+
+/**
+ * Print the results of a parallel run as a table, one row per part. Multi-line answers continue on
+ * the following lines.
+ */
+fn print_summary(results: &[DayResult]) {
+    let rows = results
+        .iter()
+        .flat_map(|result| result.rows.iter().map(move |row| (result.day, row)))
+        .map(|(day, row)| {
+            let part = row.part.map_or("-".to_string(), |part| part.to_string());
+            let answer = match &row.outcome {
+                Outcome::Answer(answer) => answer.clone(),
+                Outcome::NotImplemented => "not implemented".to_string(),
+                Outcome::Error(e) => format!("ERROR: {}", e),
+            };
+            (
+                format!("{:02}", day),
+                part,
+                answer,
+                Short(row.time).to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let width = rows
+        .iter()
+        .flat_map(|(_, _, answer, _)| answer.lines())
+        .map(|line| line.chars().count())
+        .chain(["answer".len()])
+        .max()
+        .unwrap();
+
+    println!(
+        "{:<4} {:<5} {:<width$} {:>9}",
+        "day", "part", "answer", "time"
+    );
+    for (day, part, answer, time) in rows {
+        let mut lines = answer.lines();
+        let first = lines.next().unwrap_or("");
+        println!("{:<4} {:<5} {:<width$} {:>9}", day, part, first, time);
+        for line in lines {
+            println!("{:<4} {:<5} {}", "", "", line);
+        }
+    }
+}
+
+/**
+ * Run the selected days on a pool of `jobs` threads, reporting each one on stderr as it finishes.
+ */
+fn run_parallel(selection: &Selection, jobs: usize) -> Vec<DayResult> {
+    batch::run_parallel(
+        &selection.days,
+        jobs,
+        &selection.parts,
+        &selection.source,
+        &selection.params,
+        |result| {
+            let status = if result.failed() { "failed" } else { "done" };
+            eprintln!(
+                "Day {:02} {} in {}",
+                result.day,
+                status,
+                Short(result.elapsed)
+            );
+        },
+    )
+}
+
+fn run(args: &[String]) {
+    let mut parallel = false;
+    let mut jobs = None;
+    let mut format = Format::Text;
+    let mut explain = Explain::default();
+    let selection = parse_selection(args, false, |flag, args| {
+        match flag {
+            "--parallel" => parallel = true,
+            "--explain" => explain.print = true,
+            "--explain-json" => {
+                explain.json =
+                    Some(PathBuf::from(args.next().unwrap_or_else(|| {
+                        usage_error("--explain-json expects a file path")
+                    })));
+            }
+            "--jobs" | "-j" => {
+                jobs = Some(
+                    args.next()
+                        .and_then(|n| n.parse::<usize>().ok())
+                        .filter(|n| *n > 0)
+                        .unwrap_or_else(|| usage_error("--jobs expects a positive number")),
+                );
+            }
+            "--format" | "-f" => {
+                format = args
+                    .next()
+                    .and_then(|f| Format::from_name(f))
+                    .unwrap_or_else(|| usage_error("--format expects text, json or csv"));
+            }
+            _ => return false,
+        }
+        true
+    });
+    if jobs.is_some() && !parallel {
+        usage_error("--jobs only applies with --parallel");
+    }
+    if explain.enabled() && (parallel || format != Format::Text) {
+        usage_error("--explain and --explain-json only apply to a plain text run");
+    }
+
+    if format == Format::Text && !parallel {
+        let mut failed = false;
+        for day in &selection.days {
+            failed |= !run_day(
+                day,
+                &selection.parts,
+                &selection.source,
+                &selection.params,
+                &mut explain,
+            );
+        }
+        if let Some(path) = &explain.json {
+            let json = serde_json::Value::Array(explain.records);
+            if let Err(e) = fs::write(path, serde_json::to_string_pretty(&json).unwrap() + "\n") {
+                eprintln!("Could not write {}: {}", path.display(), e);
+                failed = true;
+            }
+        }
+        if failed {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let start = Instant::now();
+    let results = if parallel {
+        let jobs = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+        run_parallel(&selection, jobs)
+    } else {
+        selection
+            .days
+            .iter()
+            .map(|day| batch::run_day(day, &selection.parts, &selection.source, &selection.params))
+            .collect()
+    };
+    let failed = results.iter().filter(|result| result.failed()).count();
+    match format {
+        Format::Text => {
+            eprintln!();
+            print_summary(&results);
+            println!(
+                "{} day(s) in {}, {} failed",
+                results.len(),
+                Short(start.elapsed()),
+                failed
+            );
+        }
+        Format::Json => {
+            let json = format::to_json(&results);
+            println!("{}", serde_json::to_string_pretty(&json).unwrap());
+        }
+        Format::Csv => print!("{}", format::to_csv(&results)),
+    }
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+fn read_baseline(path: &Path) -> Baseline {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(_) => return Baseline::default(),
+    };
+    serde_json::from_str(&contents)
+        .map_err(|e| e.to_string())
+        .and_then(|json| Baseline::from_json(&json))
+        .unwrap_or_else(|e| {
+            eprintln!("Could not read baseline {}: {}", path.display(), e);
+            process::exit(1);
+        })
+}
+
+fn bench(args: &[String]) {
+    let mut iterations = 10;
+    let mut baseline_path = PathBuf::from("bench-baseline.json");
+    let mut save = false;
+    let mut threshold = 10.0;
+    let selection = parse_selection(args, false, |flag, args| {
+        match flag {
+            "--iterations" | "-n" => {
+                iterations = args
+                    .next()
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .filter(|n| *n > 0)
+                    .unwrap_or_else(|| usage_error("--iterations expects a positive number"));
+            }
+            "--baseline" => {
+                let path = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("--baseline expects a file path"));
+                baseline_path = PathBuf::from(path);
+            }
+            "--save" => save = true,
+            "--threshold" => {
+                threshold = args
+                    .next()
+                    .and_then(|t| t.parse::<f64>().ok())
+                    .filter(|t| *t >= 0.0)
+                    .unwrap_or_else(|| usage_error("--threshold expects a percentage"));
+            }
+            _ => return false,
+        }
+        true
+    });
+
+    let mut baseline = read_baseline(&baseline_path);
+    let mut failed = false;
+    let mut regressions = 0;
+    println!(
+        "{:<6} {:<6} {:>9} {:>9} {:>9}   vs baseline",
+        "day", "stage", "min", "median", "max"
+    );
+    for day in &selection.days {
+        let input = match load_input(day, &selection.source) {
+            Some(input) => input,
+            None => {
+                failed = true;
+                continue;
+            }
+        };
+        let solution = day.solution(&day.params(&selection.params));
+        let measurements = match bench::bench_day(
+            day.day,
+            solution.as_ref(),
+            &input,
+            &selection.parts,
+            iterations,
+        ) {
+            Ok(measurements) => measurements,
+            Err(e) => {
+                eprintln!("Could not parse input: {}", e);
+                failed = true;
+                continue;
+            }
+        };
+        for m in &measurements {
+            let comparison = match baseline.get(m.day, m.stage) {
+                Some(saved) => {
+                    let change = bench::change(&m.timings, &saved.timings) * 100.0;
+                    let flag = if change > threshold {
+                        regressions += 1;
+                        "  REGRESSION"
+                    } else {
+                        ""
+                    };
+                    format!("{:+.1}% ({}){}", change, Short(saved.timings.median), flag)
+                }
+                None => "-".to_string(),
+            };
+            println!(
+                "{:<6} {:<6} {:>9} {:>9} {:>9}   {}",
+                format!("{:02}", m.day),
+                m.stage.name(),
+                Short(m.timings.min),
+                Short(m.timings.median),
+                Short(m.timings.max),
+                comparison
+            );
+        }
+        baseline.update(&measurements);
+    }
+
+    if save {
+        let json = serde_json::to_string_pretty(&baseline.to_json()).unwrap();
+        if let Err(e) = fs::write(&baseline_path, json + "\n") {
+            eprintln!("Could not write {}: {}", baseline_path.display(), e);
+            failed = true;
+        } else {
+            println!("Saved baseline to {}", baseline_path.display());
+        }
+    }
+    if regressions > 0 {
+        println!(
+            "{} stage(s) slower than the baseline by more than {}%",
+            regressions, threshold
+        );
+    }
+    if failed || regressions > 0 {
+        process::exit(1);
+    }
+}
+
+/**
+ * Print an expected or actual answer under a failed check, indenting multi-line answers.
+ */
+fn print_mismatch(label: &str, answer: &str) {
+    if answer.contains('\n') {
+        println!("  {}:\n    {}", label, answer.replace('\n', "\n    "));
+    } else {
+        println!("  {:<9} {}", format!("{}:", label), answer);
+    }
+}
+
+fn profile(args: &[String]) {
+    if !profile::ENABLED {
+        eprintln!(
+            "profile needs the counting allocator; run it with\n    cargo run --release --features alloc-profile -- profile {}",
+            args.join(" ")
+        );
+        process::exit(2);
+    }
+    let selection = parse_selection(args, false, |_, _| false);
+
+    let mut failed = false;
+    println!(
+        "{:<6} {:<6} {:>11} {:>11} {:>11}",
+        "day", "stage", "allocations", "bytes", "peak"
+    );
+    for day in &selection.days {
+        let input = match load_input(day, &selection.source) {
+            Some(input) => input,
+            None => {
+                failed = true;
+                continue;
+            }
+        };
+        let solution = day.solution(&day.params(&selection.params));
+        let profiles =
+            match profile::profile_day(day.day, solution.as_ref(), &input, &selection.parts) {
+                Ok(profiles) => profiles,
+                Err(e) => {
+                    eprintln!("Could not parse input: {}", e);
+                    failed = true;
+                    continue;
+                }
+            };
+        for p in &profiles {
+            println!(
+                "{:<6} {:<6} {:>11} {:>11} {:>11}",
+                format!("{:02}", p.day),
+                p.stage.map_or("total", |stage| stage.name()),
+                p.usage.allocations,
+                Bytes(p.usage.bytes),
+                Bytes(p.usage.peak)
+            );
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn read_answers(path: &Path) -> Result<Answers, String> {
+    match fs::read_to_string(path) {
+        Ok(text) => Answers::parse(&text).map_err(|(line, text)| {
+            format!(
+                "{}, line {}: expected `Part N: [answer]`, found {:?}",
+                path.display(),
+                line,
+                text
+            )
+        }),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(e) => Err(format!("{}: {}", path.display(), e)),
+    }
+}
+
+fn verify(args: &[String]) {
+    let mut record = false;
+    let selection = parse_selection(args, true, |flag, _| {
+        record |= flag == "--record";
+        flag == "--record"
+    });
+    if let InputSource::File(_) | InputSource::Stdin = selection.source {
+        usage_error(
+            "verify reads answers next to the inputs; use the bundled inputs or --input-dir",
+        );
+    }
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let mut errors = false;
+    for day in &selection.days {
+        let path = selection.source.answers_path(day).unwrap();
+        let mut answers = match read_answers(&path) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("Could not read answers: {}", e);
+                errors = true;
+                continue;
+            }
+        };
+        let input = match load_input(day, &selection.source) {
+            Some(input) => input,
+            None => {
+                errors = true;
+                continue;
+            }
+        };
+        let solution = day.solution(&day.params(&selection.params));
+        let parsed = match solution.parse(&input) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("Could not parse input: {}", e);
+                errors = true;
+                continue;
+            }
+        };
+
+        for &part in selection
+            .parts
+            .iter()
+            .filter(|&&part| solution.has_part(part))
+        {
+            let answer = solution.part(part, parsed.as_ref()).unwrap();
+            let label = format!("Day {:02} part {}", day.day, part);
+            if record {
+                println!("{}: recorded", label);
+                answers.set(part, answer);
+                continue;
+            }
+            match answers.get(part) {
+                Some(expected) if expected == answer => {
+                    println!("{}: pass", label);
+                    passed += 1;
+                }
+                Some(expected) => {
+                    println!("{}: FAIL", label);
+                    print_mismatch("expected", expected);
+                    print_mismatch("got", &answer);
+                    failed += 1;
+                }
+                None => {
+                    println!("{}: missing", label);
+                    missing += 1;
+                }
+            }
+        }
+
+        if record {
+            if let Err(e) = fs::write(&path, answers.to_string()) {
+                eprintln!("Could not write {}: {}", path.display(), e);
+                errors = true;
+            }
+        }
+    }
+
+    if !record {
+        println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    }
+    if failed > 0 || errors {
+        process::exit(1);
+    }
+}
+
+fn run_corpus(args: &[String]) {
+    let selection = parse_selection(args, true, |_, _| false);
+    if let InputSource::File(_) | InputSource::Stdin = selection.source {
+        usage_error("corpus reads its own inputs; use the bundled corpus or --input-dir");
+    }
+    if !selection.params.is_empty() {
+        usage_error("corpus inputs list their parameters in their answers files");
+    }
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let mut errors = false;
+    let mut inputs = 0;
+    for day in &selection.days {
+        let dir = selection.source.corpus_dir(day).unwrap();
+        let entries = match corpus::entries(&dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                eprintln!("Could not read {}: {}", dir.display(), e);
+                errors = true;
+                continue;
+            }
+        };
+        if entries.is_empty() {
+            if selection.days.len() == 1 {
+                println!("Day {:02}: no inputs in {}", day.day, dir.display());
+            }
+            continue;
+        }
+
+        for entry in &entries {
+            inputs += 1;
+            let checks = batch::quietly(|| corpus::check(day, entry, &selection.parts));
+            for check in checks {
+                let label = match check.row.part {
+                    Some(part) => format!("Day {:02} {} part {}", day.day, entry.name, part),
+                    None => format!("Day {:02} {}", day.day, entry.name),
+                };
+                let status = match &check.status {
+                    Status::Pass => "pass",
+                    Status::Fail { .. } => "FAIL",
+                    Status::Missing => "missing",
+                    Status::Error => "ERROR",
+                };
+                println!("{}: {} ({})", label, status, Short(check.row.time));
+                match (&check.status, &check.row.outcome) {
+                    (Status::Pass, _) => passed += 1,
+                    (Status::Missing, _) => missing += 1,
+                    (Status::Fail { expected }, Outcome::Answer(answer)) => {
+                        print_mismatch("expected", expected);
+                        print_mismatch("got", answer);
+                        failed += 1;
+                    }
+                    (_, Outcome::Error(e)) => {
+                        println!("  {}", e);
+                        failed += 1;
+                    }
+                    _ => failed += 1,
+                }
+            }
+        }
+    }
+
+    println!(
+        "{} inputs: {} passed, {} failed, {} missing",
+        inputs, passed, failed, missing
+    );
+    if failed > 0 || errors {
+        process::exit(1);
+    }
+}
+
+fn visualize(args: &[String]) {
+    let mut fps = 10.0;
+    let mut dump = None;
+    let selection = parse_selection(args, false, |flag, args| {
+        match flag {
+            "--fps" => {
+                fps = args
+                    .next()
+                    .and_then(|n| n.parse::<f64>().ok())
+                    .filter(|n| *n > 0.0)
+                    .unwrap_or_else(|| usage_error("--fps expects a positive number"));
+            }
+            "--dump" => {
+                let dir = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("--dump expects a directory"));
+                dump = Some(PathBuf::from(dir));
+            }
+            _ => return false,
+        }
+        true
+    });
+    let day = &selection.days[0];
+    // without --part, show part 1
+    let part = selection.parts[0];
+    let visualization = match &day.visualization {
+        Some(visualization) if visualization.parts.contains(&part) => visualization,
+        Some(_) => usage_error(&format!(
+            "Day {} part {} can't be visualized",
+            day.day, part
+        )),
+        None => usage_error(&format!("Day {} can't be visualized", day.day)),
+    };
+    if dump.is_none() {
+        if let InputSource::Stdin = selection.source {
+            usage_error("The player reads its commands from stdin; use --dump with `--input -`");
+        }
+    }
+
+    let input = load_input(day, &selection.source).unwrap_or_else(|| process::exit(1));
+    let result = match dump {
+        Some(dir) => {
+            let mut frames = FrameDump::new(&dir).unwrap_or_else(|e| {
+                eprintln!("Could not create {}: {}", dir.display(), e);
+                process::exit(1);
+            });
+            let result = (visualization.run)(&input, part, &mut frames);
+            match frames.finish() {
+                Ok(count) => println!("Wrote {} frame(s) to {}", count, dir.display()),
+                Err(e) => {
+                    eprintln!("Could not write frames: {}", e);
+                    process::exit(1);
+                }
+            }
+            result
+        }
+        None => (visualization.run)(&input, part, &mut Player::new(fps)),
+    };
+    if let Err(e) = result {
+        eprintln!("Could not parse input: {}", e);
+        process::exit(1);
+    }
+}
+
+fn generate(args: &[String]) {
+    let mut day = None;
+    let mut size = 10;
+    let mut seed = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" | "-n" => {
+                size = args
+                    .next()
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .unwrap_or_else(|| usage_error("--size expects a number"));
+            }
+            "--seed" => {
+                seed = Some(
+                    args.next()
+                        .and_then(|n| n.parse::<u64>().ok())
+                        .unwrap_or_else(|| usage_error("--seed expects a number")),
+                );
+            }
+            _ => match arg.parse::<u8>() {
+                Ok(d) if day.is_none() => day = Some(d),
+                _ => usage_error(&format!("Unexpected argument: {}", arg)),
+            },
+        }
+    }
+    let day = day.unwrap_or_else(|| usage_error("Missing day"));
+    let day =
+        days::get(day).unwrap_or_else(|| usage_error(&format!("No solution for day {}", day)));
+    let seed = seed.unwrap_or_else(|| {
+        let seed = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |time| time.as_nanos() as u64);
+        eprintln!("Seed: {}", seed);
+        seed
+    });
+
+    println!("{}", (day.generate)(&mut Rng::new(seed), size));
+}
+
+fn check_input(args: &[String]) {
+    let (day, file) = match args {
+        [day, file] => (day, file),
+        [_, _, arg, ..] => usage_error(&format!("Unexpected argument: {}", arg)),
+        _ => usage_error("check-input needs a day and a file"),
+    };
+    let day = day
+        .parse::<u8>()
+        .ok()
+        .and_then(days::get)
+        .unwrap_or_else(|| usage_error(&format!("No solution for day {}", day)));
+    let source = match file.as_str() {
+        "-" => InputSource::Stdin,
+        file => InputSource::File(PathBuf::from(file)),
+    };
+    let input = load_input(&day, &source).unwrap_or_else(|| process::exit(1));
+
+    let report = validate::check(&day, &input);
+    println!("Day {:02}", day.day);
+    println!("{}", report.stats);
+    match report.records {
+        Records::Lines => println!("Checked {} line(s) one at a time", report.checked),
+        Records::Blocks => println!("Checked {} block(s) one at a time", report.checked),
+        Records::Whole => println!("Checked the input as a whole"),
+    }
+    if report.problems.is_empty() {
+        println!("No problems found");
+        return;
+    }
+    println!("{} problem(s):", report.problems.len());
+    for problem in &report.problems {
+        println!("  {}", problem);
+    }
+    process::exit(1);
+}
+
+fn new_day(args: &[String]) {
+    let day = match args {
+        [day] => day
+            .parse::<u8>()
+            .unwrap_or_else(|_| usage_error(&format!("Unexpected argument: {}", day))),
+        [] => usage_error("Missing day"),
+        [_, arg, ..] => usage_error(&format!("Unexpected argument: {}", arg)),
+    };
+    match scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), day) {
+        Ok(changes) => {
+            for change in changes {
+                println!("{}", change);
+            }
+        }
+        Err(e) => {
+            eprintln!("Could not add day {}: {}", day, e);
+            process::exit(1);
+        }
+    }
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    match args.first().map(String::as_str) {
+        Some("list") => list(),
+        Some("run") => run(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("profile") => profile(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("corpus") => run_corpus(&args[1..]),
+        Some("visualize") => visualize(&args[1..]),
+        Some("gen") => generate(&args[1..]),
+        Some("check-input") => check_input(&args[1..]),
+        Some("new") => new_day(&args[1..]),
+        Some(command) => usage_error(&format!("Unknown command: {}", command)),
+        None => usage_error("Missing command"),
+    }
 }
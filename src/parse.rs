@@ -0,0 +1,558 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/**
+ * A puzzle input that didn't match the format a day's parser expects.
+ *
+ * Lines and columns are 1-based, like an editor shows them. Errors for something missing have empty
+ * text; if the input ended early they point at the line after the last one.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(
+        day: u8,
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> ParseError {
+        ParseError {
+            day,
+            line,
+            column,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /**
+     * An error covering the whole of `text`, which is line `line` of the input.
+     */
+    pub fn line(day: u8, line: usize, text: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(day, line, 1, text, expected)
+    }
+
+    /**
+     * An error covering `part`, a slice of `text`, which is line `line` of the input. The column
+     * is where `part` starts within `text`.
+     */
+    pub fn within(
+        day: u8,
+        line: usize,
+        text: &str,
+        part: &str,
+        expected: impl Into<String>,
+    ) -> ParseError {
+        ParseError::new(day, line, column_of(text, part), part, expected)
+    }
+
+    /**
+     * An error covering the single character at byte offset `offset` of `text`, which is line
+     * `line` of the input.
+     */
+    pub fn char_at(
+        day: u8,
+        line: usize,
+        text: &str,
+        offset: usize,
+        expected: impl Into<String>,
+    ) -> ParseError {
+        let end = offset + text[offset..].chars().next().map_or(0, char::len_utf8);
+        ParseError::within(day, line, text, &text[offset..end], expected)
+    }
+
+    /**
+     * An error for input that ended early; `lines` is how many lines the input had.
+     */
+    pub fn end_of_input(day: u8, lines: usize, expected: impl Into<String>) -> ParseError {
+        ParseError::new(day, lines + 1, 1, "", expected)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: expected {}, found ",
+            self.day, self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "nothing")
+        } else {
+            write!(f, "{:?}", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/**
+ * The 1-based column where `part` starts in `text`. `part` should be a slice of `text`; if it isn't,
+ * fall back to searching for it, and then to the first column.
+ */
+fn column_of(text: &str, part: &str) -> usize {
+    let start = text.as_ptr() as usize;
+    let offset = (part.as_ptr() as usize).wrapping_sub(start);
+    let offset = if offset <= text.len() {
+        offset
+    } else {
+        text.find(part).unwrap_or(0)
+    };
+    text[..offset].chars().count() + 1
+}
+
+/**
+ * The lines of an input, numbered from 1 like error lines are.
+ */
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+}
+
+/**
+ * A slice of one line of the input, which knows where it is so errors about it can point at it.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field<'a> {
+    day: u8,
+    number: usize,
+    line: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Field<'a> {
+    /**
+     * The whole of line `number`.
+     */
+    pub fn line(day: u8, number: usize, line: &'a str) -> Field<'a> {
+        Field {
+            day,
+            number,
+            line,
+            text: line,
+        }
+    }
+
+    /**
+     * A smaller field for `text`, which must be a slice of this one.
+     */
+    fn slice(&self, text: &'a str) -> Field<'a> {
+        Field { text, ..*self }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /**
+     * The line number this field is on.
+     */
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    /**
+     * An error pointing at this field.
+     */
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::within(self.day, self.number, self.line, self.text, expected)
+    }
+
+    /**
+     * Parse the field as a number without a sign.
+     */
+    pub fn integer<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        if self.text.is_empty() || !self.text.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(self.error(expected));
+        }
+        self.text.parse().map_err(|_| self.error(expected))
+    }
+
+    /**
+     * Parse the field as a number, which may start with `-`.
+     */
+    pub fn signed<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        let digits = self.text.strip_prefix('-').unwrap_or(self.text);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(self.error(expected));
+        }
+        self.text.parse().map_err(|_| self.error(expected))
+    }
+
+    /**
+     * Pick the value paired with the field's text, like an operator's function.
+     */
+    pub fn one_of<T: Clone>(&self, options: &[(&str, T)], expected: &str) -> Result<T, ParseError> {
+        options
+            .iter()
+            .find(|(text, _)| *text == self.text)
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| self.error(expected))
+    }
+
+    /**
+     * Split the field into a list, like `79, 98` with the separator `, `. An empty field is an
+     * empty list.
+     */
+    pub fn list(&self, separator: &str) -> Vec<Field<'a>> {
+        if self.text.is_empty() {
+            return vec![];
+        }
+        self.text
+            .split(separator)
+            .map(|text| self.slice(text))
+            .collect()
+    }
+
+    /**
+     * Split the field into exactly `N` parts, like `x=2, y=18` into `x=2` and `y=18`.
+     */
+    pub fn split_n<const N: usize>(
+        &self,
+        separator: &str,
+        expected: &str,
+    ) -> Result<[Field<'a>; N], ParseError> {
+        self.text
+            .split(separator)
+            .map(|text| self.slice(text))
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| self.error(expected))
+    }
+
+    /**
+     * The value of a `key=value` field, checking that the key is `key`.
+     */
+    pub fn key_value(&self, key: &str) -> Result<Field<'a>, ParseError> {
+        match self.text.split_once('=') {
+            Some((found, value)) if found == key => Ok(self.slice(value)),
+            _ => Err(self.error(format!("`{}=[value]`", key))),
+        }
+    }
+
+    /**
+     * The rest of the field after `prefix`, if it starts with it.
+     */
+    pub fn strip_prefix(&self, prefix: &str) -> Option<Field<'a>> {
+        self.text.strip_prefix(prefix).map(|text| self.slice(text))
+    }
+
+    /**
+     * Split the field into numbers and single characters, like a path `10R5L5` into `10`, `R`,
+     * `5`, `L` and `5`.
+     */
+    pub fn tokens(&self) -> Vec<Field<'a>> {
+        let mut tokens = vec![];
+        let mut rest = self.text;
+        while let Some(c) = rest.chars().next() {
+            let length = match rest.find(|c: char| !c.is_ascii_digit()) {
+                _ if !c.is_ascii_digit() => c.len_utf8(),
+                Some(end) => end,
+                None => rest.len(),
+            };
+            tokens.push(self.slice(&rest[..length]));
+            rest = &rest[length..];
+        }
+        tokens
+    }
+}
+
+/**
+ * A run of lines between blank lines, like one monkey's notes on day 11, read one line at a time.
+ */
+#[derive(Debug, Clone)]
+pub struct Block<'a> {
+    day: u8,
+    lines: Vec<(usize, &'a str)>,
+    next: usize,
+}
+
+/**
+ * Split the input into blocks of lines separated by blank lines.
+ */
+pub fn blocks(day: u8, input: &str) -> Vec<Block<'_>> {
+    let mut blocks = vec![];
+    let mut block = vec![];
+    for (number, line) in lines(input) {
+        if line.trim().is_empty() {
+            if !block.is_empty() {
+                blocks.push(Block::new(day, std::mem::take(&mut block)));
+            }
+        } else {
+            block.push((number, line));
+        }
+    }
+    if !block.is_empty() {
+        blocks.push(Block::new(day, block));
+    }
+    blocks
+}
+
+impl<'a> Block<'a> {
+    fn new(day: u8, lines: Vec<(usize, &'a str)>) -> Block<'a> {
+        Block {
+            day,
+            lines,
+            next: 0,
+        }
+    }
+
+    /**
+     * Every line of the block, numbered.
+     */
+    pub fn lines(&self) -> &[(usize, &'a str)] {
+        &self.lines
+    }
+
+    /**
+     * Match the block's next line against `pattern`.
+     */
+    pub fn next<const N: usize>(
+        &mut self,
+        pattern: &Pattern,
+    ) -> Result<[Field<'a>; N], ParseError> {
+        let Some(&(number, line)) = self.lines.get(self.next) else {
+            let last = self.lines.last().map_or(0, |&(number, _)| number);
+            return Err(ParseError::end_of_input(
+                self.day,
+                last,
+                pattern.description(),
+            ));
+        };
+        self.next += 1;
+        pattern.parse(number, line)
+    }
+
+    /**
+     * Check that every line of the block has been read.
+     */
+    pub fn finish(&self) -> Result<(), ParseError> {
+        match self.lines.get(self.next) {
+            Some(&(number, line)) => Err(ParseError::line(self.day, number, line, "a blank line")),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Literal(String),
+    Hole(String),
+}
+
+/**
+ * A fixed line format, like `Sensor at x={x}, y={y}`: literal text with `{name}` holes that capture
+ * whatever is between the text around them. Build one once and use it for every line.
+ *
+ * A hole runs up to the first place the text after it appears, or to the end of the line if it's
+ * last, so two holes can't be next to each other.
+ */
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    day: u8,
+    tokens: Vec<Token>,
+}
+
+impl Pattern {
+    pub fn new(day: u8, template: &str) -> Pattern {
+        let mut tokens = vec![];
+        let mut rest = template;
+        while !rest.is_empty() {
+            match rest.find('{') {
+                Some(0) => {
+                    let end = rest.find('}').expect("unclosed hole in pattern");
+                    assert!(
+                        !matches!(tokens.last(), Some(Token::Hole(_))),
+                        "holes in a pattern need text between them"
+                    );
+                    tokens.push(Token::Hole(rest[1..end].to_string()));
+                    rest = &rest[end + 1..];
+                }
+                start => {
+                    let end = start.unwrap_or(rest.len());
+                    tokens.push(Token::Literal(rest[..end].to_string()));
+                    rest = &rest[end..];
+                }
+            }
+        }
+        Pattern { day, tokens }
+    }
+
+    /**
+     * The format as error messages show it, like `` `Sensor at x=[x], y=[y]` ``.
+     */
+    pub fn description(&self) -> String {
+        let text = self
+            .tokens
+            .iter()
+            .map(|token| match token {
+                Token::Literal(text) => text.clone(),
+                Token::Hole(name) => format!("[{}]", name),
+            })
+            .collect::<String>();
+        format!("`{}`", text.trim())
+    }
+
+    /**
+     * Match line `number` against the pattern, returning what each of its `N` holes captured.
+     */
+    pub fn parse<'a, const N: usize>(
+        &self,
+        number: usize,
+        line: &'a str,
+    ) -> Result<[Field<'a>; N], ParseError> {
+        self.parse_field(Field::line(self.day, number, line))
+    }
+
+    /**
+     * Match a field against the pattern, returning what each of its `N` holes captured.
+     */
+    pub fn parse_field<'a, const N: usize>(
+        &self,
+        field: Field<'a>,
+    ) -> Result<[Field<'a>; N], ParseError> {
+        let holes = self
+            .tokens
+            .iter()
+            .filter(|token| matches!(token, Token::Hole(_)))
+            .count();
+        assert_eq!(holes, N, "pattern has {} holes, not {}", holes, N);
+
+        let text = field.text;
+        // point errors at everything from where the match failed
+        let mismatch = |offset: usize| field.slice(&text[offset..]).error(self.description());
+        let mut captures = vec![];
+        let mut offset = 0;
+        for (index, token) in self.tokens.iter().enumerate() {
+            match token {
+                Token::Literal(literal) => {
+                    if !text[offset..].starts_with(literal.as_str()) {
+                        return Err(mismatch(offset));
+                    }
+                    offset += literal.len();
+                }
+                Token::Hole(_) => {
+                    let end = match self.tokens.get(index + 1) {
+                        Some(Token::Literal(next)) => match text[offset..].find(next.as_str()) {
+                            Some(length) => offset + length,
+                            None => return Err(mismatch(offset)),
+                        },
+                        _ => text.len(),
+                    };
+                    captures.push(field.slice(&text[offset..end]));
+                    offset = end;
+                }
+            }
+        }
+        if offset != text.len() {
+            return Err(mismatch(offset));
+        }
+        Ok(captures.try_into().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_within() {
+        let text = "move 1 from x to 3";
+        let part = text.split(' ').nth(3).unwrap();
+        let error = ParseError::within(5, 12, text, part, "a stack number");
+        assert_eq!(error.column, 13);
+        assert_eq!(error.text, "x");
+        assert_eq!(
+            error.to_string(),
+            "day 5, line 12, column 13: expected a stack number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_end_of_input() {
+        let error = ParseError::end_of_input(12, 5, "a start position `S`");
+        assert_eq!(error.line, 6);
+        assert_eq!(
+            error.to_string(),
+            "day 12, line 6, column 1: expected a start position `S`, found nothing"
+        );
+    }
+
+    #[test]
+    fn test_pattern() {
+        let pattern = Pattern::new(15, "Sensor at x={x}, y={y}: beacon {beacon}");
+        let line = "Sensor at x=-2, y=18: beacon x=1, y=2";
+        let [x, y, beacon] = pattern.parse(3, line).unwrap();
+        assert_eq!(x.signed::<i32>("a coordinate"), Ok(-2));
+        assert_eq!(y.integer::<u8>("a coordinate"), Ok(18));
+        let [bx, by] = beacon.split_n(", ", "two coordinates").unwrap();
+        assert_eq!(bx.key_value("x").unwrap().text(), "1");
+        assert_eq!(
+            by.key_value("x").unwrap_err(),
+            ParseError::new(15, 3, 35, "y=2", "`x=[value]`")
+        );
+
+        assert_eq!(
+            pattern.parse::<3>(1, "Sensor at x=1; y=2").unwrap_err(),
+            ParseError::new(
+                15,
+                1,
+                13,
+                "1; y=2",
+                "`Sensor at x=[x], y=[y]: beacon [beacon]`"
+            )
+        );
+        assert_eq!(
+            x.integer::<i32>("a coordinate").unwrap_err(),
+            ParseError::new(15, 3, 13, "-2", "a coordinate")
+        );
+    }
+
+    #[test]
+    fn test_fields() {
+        let field = Field::line(22, 1, "10R5LL");
+        let tokens = field.tokens();
+        let texts = tokens.iter().map(Field::text).collect::<Vec<_>>();
+        assert_eq!(texts, ["10", "R", "5", "L", "L"]);
+        assert_eq!(tokens[3].error("a number").column, 5);
+
+        let items = Field::line(11, 2, "79, 98").list(", ");
+        assert_eq!(items[1].integer::<u32>("an item"), Ok(98));
+        assert!(Field::line(11, 2, "").list(", ").is_empty());
+        assert_eq!(
+            Field::line(11, 2, "*").one_of(&[("+", 1), ("*", 2)], "an operator"),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let line = Pattern::new(1, "n={n}");
+        let mut blocks = blocks(1, "n=1\nn=2\n\n\nn=3\nx");
+        assert_eq!(blocks.len(), 2);
+        let [n] = blocks[0].next(&line).unwrap();
+        assert_eq!(n.integer::<u8>("a number"), Ok(1));
+        assert_eq!(
+            blocks[0].finish(),
+            Err(ParseError::line(1, 2, "n=2", "a blank line"))
+        );
+        assert_eq!(blocks[1].lines()[0], (5, "n=3"));
+        blocks[1].next::<1>(&line).unwrap();
+        assert_eq!(blocks[1].next::<1>(&line).unwrap_err().line, 6);
+        assert_eq!(
+            blocks[1].next::<1>(&line).unwrap_err(),
+            ParseError::end_of_input(1, 6, "`n=[n]`")
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::bench::Stage;
+use super::parse::ParseError;
+use super::solution::DynSolution;
+
+/**
+ * Whether the counting allocator is installed. It only is when the crate is built with the
+ * `alloc-profile` feature; otherwise every measurement comes out as zero.
+ */
+pub const ENABLED: bool = cfg!(feature = "alloc-profile");
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/**
+ * The system allocator, counting every allocation and how much memory is live. Reallocations count
+ * as a new allocation of the new size.
+ */
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn grow(&self, size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.grow(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        self.grow(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+        self.grow(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[cfg(feature = "alloc-profile")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/**
+ * What a piece of code allocated while it ran.
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// How many allocations (and reallocations) it made
+    pub allocations: usize,
+    /// The total size of those allocations, whether or not they were freed
+    pub bytes: usize,
+    /// The most memory it had live at once, on top of what was live when it started
+    pub peak: usize,
+}
+
+/**
+ * Run `f`, counting what it allocates. Measurements can be nested. The counts are global, so
+ * anything running on other threads at the same time is counted too.
+ */
+pub fn measure<T>(f: impl FnOnce() -> T) -> (Usage, T) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    // track the peak from here, putting back the outer measurement's peak afterwards
+    let outer_peak = PEAK.swap(live, Ordering::Relaxed);
+
+    let result = f();
+
+    let peak = PEAK.fetch_max(outer_peak, Ordering::Relaxed);
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: peak.saturating_sub(live),
+    };
+    (usage, result)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Profile {
+    pub day: u8,
+    /// The stage measured, or None for the whole day
+    pub stage: Option<Stage>,
+    pub usage: Usage,
+}
+
+/**
+ * Measure parsing the input, then each of the requested parts on the parsed result, followed by
+ * the day as a whole. Parts that aren't implemented are skipped.
+ */
+pub fn profile_day(
+    day: u8,
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[u8],
+) -> Result<Vec<Profile>, ParseError> {
+    let (total, profiles) = measure(|| {
+        let mut profiles = vec![];
+
+        let (usage, parsed) = measure(|| solution.parse(input));
+        let parsed = parsed?;
+        profiles.push(Profile {
+            day,
+            stage: Some(Stage::Parse),
+            usage,
+        });
+
+        for &part in parts.iter().filter(|&&part| solution.has_part(part)) {
+            let (usage, _) = measure(|| solution.part(part, parsed.as_ref()));
+            profiles.push(Profile {
+                day,
+                stage: Some(Stage::part(part)),
+                usage,
+            });
+        }
+        Ok(profiles)
+    });
+
+    let mut profiles = profiles?;
+    profiles.push(Profile {
+        day,
+        stage: None,
+        usage: total,
+    });
+    Ok(profiles)
+}
+
+/**
+ * Displays a number of bytes compactly in the largest fitting unit, like `812B`, `45.2KiB` or
+ * `1.50GiB`.
+ */
+pub struct Bytes(pub usize);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self.0 as f64;
+        let text = if self.0 < 1 << 10 {
+            format!("{}B", self.0)
+        } else if self.0 < 1 << 20 {
+            format!("{:.1}KiB", bytes / (1 << 10) as f64)
+        } else if self.0 < 1 << 30 {
+            format!("{:.1}MiB", bytes / (1 << 20) as f64)
+        } else {
+            format!("{:.2}GiB", bytes / (1 << 30) as f64)
+        };
+        f.pad(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc-profile")]
+    #[test]
+    fn test_measure() {
+        let (outer, inner) = measure(|| {
+            let (inner, numbers) = measure(|| vec![0u64; 1000]);
+            drop(numbers);
+            inner
+        });
+        assert!(inner.allocations >= 1);
+        assert!(inner.bytes >= 8000);
+        assert!(inner.peak >= 8000);
+        assert!(outer.peak >= inner.peak);
+        assert!(outer.bytes >= inner.bytes);
+    }
+
+    #[cfg(not(feature = "alloc-profile"))]
+    #[test]
+    fn test_disabled() {
+        let (usage, _) = measure(|| vec![0u64; 1000]);
+        assert_eq!(usage, Usage::default());
+    }
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(Bytes(812).to_string(), "812B");
+        assert_eq!(Bytes(46_285).to_string(), "45.2KiB");
+        assert_eq!(Bytes(1_610_612_736).to_string(), "1.50GiB");
+    }
+}
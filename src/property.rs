@@ -0,0 +1,179 @@
+use std::fmt::Debug;
+
+use super::random::Rng;
+
+/**
+ * A property that didn't hold, shrunk to the smallest input that still breaks it.
+ */
+#[derive(Debug, Clone)]
+pub struct Failure<T> {
+    /// The seed of the case that first failed
+    pub seed: u64,
+    /// The smallest failing input found by shrinking
+    pub input: T,
+    /// Why the property failed on `input`
+    pub message: String,
+    /// How many smaller inputs were accepted while shrinking
+    pub shrinks: usize,
+}
+
+/**
+ * Runs a property against many random inputs, shrinking the first input that breaks it.
+ *
+ * Each case gets its own seed, and sizes cycle from 1 up to `max_size`, so small inputs are
+ * covered as often as large ones.
+ */
+#[derive(Debug, Clone)]
+pub struct Checker {
+    pub cases: usize,
+    pub max_size: usize,
+    pub seed: u64,
+}
+
+impl Default for Checker {
+    fn default() -> Checker {
+        Checker {
+            cases: 1000,
+            max_size: 20,
+            seed: 0,
+        }
+    }
+}
+
+impl Checker {
+    /**
+     * Check that `property` holds for every generated input. It returns `Err` with a reason when
+     * it doesn't.
+     *
+     * On failure, `shrink` is asked for smaller versions of the input, and the first one that
+     * still fails replaces it, until none of the candidates fail. `shrink` should only return
+     * inputs the generator could have made, so the property isn't tested on invalid input.
+     */
+    pub fn check<T: Clone + Debug>(
+        &self,
+        generate: impl Fn(&mut Rng, usize) -> T,
+        shrink: impl Fn(&T) -> Vec<T>,
+        property: impl Fn(&T) -> Result<(), String>,
+    ) -> Result<(), Failure<T>> {
+        for case in 0..self.cases {
+            let seed = self.seed.wrapping_add(case as u64);
+            let size = case % self.max_size.max(1) + 1;
+            let input = generate(&mut Rng::new(seed), size);
+            if let Err(message) = property(&input) {
+                let mut failure = Failure {
+                    seed,
+                    input,
+                    message,
+                    shrinks: 0,
+                };
+                'shrink: loop {
+                    for candidate in shrink(&failure.input) {
+                        if let Err(message) = property(&candidate) {
+                            failure.input = candidate;
+                            failure.message = message;
+                            failure.shrinks += 1;
+                            continue 'shrink;
+                        }
+                    }
+                    break;
+                }
+                return Err(failure);
+            }
+        }
+        Ok(())
+    }
+}
+
+/**
+ * Compare two answers for the same input, for checking an optimized solver against a reference.
+ */
+pub fn agree<A: PartialEq + Debug>(reference: A, optimized: A) -> Result<(), String> {
+    if reference == optimized {
+        Ok(())
+    } else {
+        Err(format!(
+            "reference gave {:?}, optimized gave {:?}",
+            reference, optimized
+        ))
+    }
+}
+
+/**
+ * Smaller versions of a list: without each half, then without each single item. Lists shorter
+ * than `min_len` aren't returned.
+ */
+pub fn shrink_vec<T: Clone>(items: &[T], min_len: usize) -> Vec<Vec<T>> {
+    let mut candidates = vec![];
+    let half = items.len() / 2;
+    if half > 0 {
+        candidates.push(items[half..].to_vec());
+        candidates.push(items[..items.len() - half].to_vec());
+    }
+    for index in 0..items.len() {
+        let mut smaller = items.to_vec();
+        smaller.remove(index);
+        candidates.push(smaller);
+    }
+    candidates.retain(|candidate| candidate.len() >= min_len);
+    candidates
+}
+
+/**
+ * Numbers closer to zero than `n`: zero itself, half of `n`, and one step closer.
+ */
+pub fn shrink_number(n: i64) -> Vec<i64> {
+    let mut candidates = vec![0, n / 2, n - n.signum()];
+    candidates.dedup();
+    candidates.retain(|&candidate| candidate != n);
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_passes() {
+        let checker = Checker::default();
+        let result = checker.check(
+            |rng, size| (0..size).map(|_| rng.range(0..=9)).collect::<Vec<_>>(),
+            |items| shrink_vec(items, 0),
+            |items| agree(items.iter().sum::<i64>(), items.iter().rev().sum()),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_shrinks_to_minimal_input() {
+        let checker = Checker::default();
+        let failure = checker
+            .check(
+                |rng, size| (0..size).map(|_| rng.range(-50..=50)).collect::<Vec<_>>(),
+                |items| {
+                    let mut candidates = shrink_vec(items, 0);
+                    for (index, &n) in items.iter().enumerate() {
+                        candidates.extend(shrink_number(n).into_iter().map(|smaller| {
+                            let mut items = items.clone();
+                            items[index] = smaller;
+                            items
+                        }));
+                    }
+                    candidates
+                },
+                |items| match items.iter().any(|&n| n > 10) {
+                    true => Err("too big".to_string()),
+                    false => Ok(()),
+                },
+            )
+            .unwrap_err();
+        assert_eq!(failure.input, vec![11]);
+        assert!(failure.shrinks > 0);
+    }
+
+    #[test]
+    fn test_shrink_number() {
+        assert_eq!(shrink_number(0), Vec::<i64>::new());
+        assert_eq!(shrink_number(1), vec![0]);
+        assert_eq!(shrink_number(-9), vec![0, -4, -8]);
+    }
+}
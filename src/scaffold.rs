@@ -0,0 +1,339 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+/**
+ * The files of a new day's module, relative to `src/dayNN`. `NN` is replaced by the two-digit day
+ * and `{day}` by the plain one. `mod.rs` comes after them and after the registrations, since it's
+ * what marks the day as existing.
+ */
+const FILES: [(&str, &str); 9] = [
+    ("inputs/input.txt", ""),
+    ("inputs/answers.txt", ""),
+    ("inputs/sample.txt", ""),
+    ("inputs/sample.answers.txt", ""),
+    (
+        "shared.rs",
+        r#"use crate::parse::{lines, ParseError};
+
+/**
+ * Parse the puzzle input, for now one string per line.
+ */
+pub fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(lines(input).map(|(_, line)| line.to_string()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let demo = include_str!("inputs/sample.txt");
+        assert!(parse_input(demo).is_ok());
+    }
+}
+"#,
+    ),
+    (
+        "part1.rs",
+        r#"use super::shared::*;
+use crate::parse::ParseError;
+use crate::solution::Unsolved;
+
+pub fn solve(_lines: &[String]) -> Unsolved {
+    Unsolved
+}
+
+pub fn run(input: &str) -> Result<Unsolved, ParseError> {
+    Ok(solve(&parse_input(input)?))
+}
+"#,
+    ),
+    (
+        "part2.rs",
+        r#"use super::shared::*;
+use crate::parse::ParseError;
+use crate::solution::Unsolved;
+
+pub fn solve(_lines: &[String]) -> Unsolved {
+    Unsolved
+}
+
+pub fn run(input: &str) -> Result<Unsolved, ParseError> {
+    Ok(solve(&parse_input(input)?))
+}
+"#,
+    ),
+    (
+        "generate.rs",
+        r#"use itertools::Itertools;
+
+use crate::random::Rng;
+
+/**
+ * `size` lines of random numbers, until there's a generator shaped like the puzzle's input.
+ */
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| rng.range(1..=1000).to_string())
+        .join("\n")
+}
+"#,
+    ),
+    (
+        "main.rs",
+        r#"use aoc::dayNN::{part1, part2};
+use aoc::parse::ParseError;
+
+fn main() -> Result<(), ParseError> {
+    let input = include_str!("inputs/input.txt");
+
+    // unsolved parts print as `Unsolved`
+    println!("Part 1: {:?}", part1::run(input)?);
+    println!("Part 2: {:?}", part2::run(input)?);
+
+    Ok(())
+}
+"#,
+    ),
+];
+
+const MOD_RS: &str = r#"pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod shared;
+
+use crate::parse::ParseError;
+use crate::solution::{Solution, Unsolved};
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    type Parsed = Vec<String>;
+    type Part1 = Unsolved;
+    type Part2 = Unsolved;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, ParseError> {
+        shared::parse_input(input)
+    }
+    fn part1(&self, lines: &Vec<String>) -> Unsolved {
+        part1::solve(lines)
+    }
+    fn part2(&self, lines: &Vec<String>) -> Unsolved {
+        part2::solve(lines)
+    }
+}
+"#;
+
+const DAY_ENTRY: &str = r#"        Day {
+            day: {day},
+            input: include_str!("dayNN/inputs/input.txt"),
+            records: Records::Lines,
+            params: &[],
+            solution: |_| Box::new(dayNN::DayNN),
+            generate: dayNN::generate::input,
+            visualization: None,
+            explanation: None,
+        },
+"#;
+
+const BIN_ENTRY: &str = r#"[[bin]]
+name = "dayNN"
+path = "src/dayNN/main.rs"
+"#;
+
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("NN", &format!("{:02}", day))
+        .replace("{day}", &day.to_string())
+}
+
+/**
+ * Add a new day to the crate rooted at `root`: create `src/dayNN` with a module skeleton, a test
+ * stub and empty inputs, and register the day in `src/lib.rs`, the runner's list in `src/days.rs`
+ * and a `[[bin]]` in `Cargo.toml`. Returns a description of each change made.
+ *
+ * Days that already have a `mod.rs` are refused. It's written last, after the registrations, so a
+ * run interrupted at any point before it can simply be repeated: files that already exist are kept
+ * and registrations already in place are skipped.
+ */
+pub fn new_day(root: &Path, day: u8) -> io::Result<Vec<String>> {
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("there is no day {}", day),
+        ));
+    }
+    let dir = root.join("src").join(format!("day{:02}", day));
+    if dir.join("mod.rs").exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("day {} already exists in {}", day, dir.display()),
+        ));
+    }
+
+    // register the day first and write mod.rs last, so until the day is refused as existing, every
+    // step is still to come or can be repeated
+    let mut changes = vec![];
+    let lib = root.join("src/lib.rs");
+    let line = format!("pub mod day{:02};\n", day);
+    if register(&lib, day, &line, "pub mod day", "pub mod ")? {
+        changes.push(format!("added day{:02} to {}", day, lib.display()));
+    }
+    let days = root.join("src/days.rs");
+    if register(
+        &days,
+        day,
+        &fill(DAY_ENTRY, day),
+        "        Day {\n            day: ",
+        "    ]\n}",
+    )? {
+        changes.push(format!("added day {} to {}", day, days.display()));
+    }
+    let manifest = root.join("Cargo.toml");
+    let bin = fill(BIN_ENTRY, day) + "\n";
+    if register(&manifest, day, &bin, "[[bin]]\nname = \"day", "")? {
+        changes.push(format!(
+            "added the day{:02} binary to {}",
+            day,
+            manifest.display()
+        ));
+    }
+
+    for (name, template) in FILES.iter().chain([("mod.rs", MOD_RS)].iter()) {
+        let path = dir.join(name);
+        if create(&path, &fill(template, day))? {
+            changes.push(format!("created {}", path.display()));
+        }
+    }
+    Ok(changes)
+}
+
+/**
+ * Write `contents` to a new file at `path`, returning false without touching it if it exists.
+ */
+fn create(path: &Path, contents: &str) -> io::Result<bool> {
+    fs::create_dir_all(path.parent().unwrap())?;
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(mut file) => {
+            file.write_all(contents.as_bytes())?;
+            Ok(true)
+        }
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/**
+ * Insert `entry` into the file at `path`, in order among the entries for other days, which each
+ * start with `marker` followed by their day number. With no later day, it goes before the first
+ * `end` after the last entry (or at the end of the file if `end` is empty). Returns false if
+ * `entry` is already there.
+ */
+fn register(path: &Path, day: u8, entry: &str, marker: &str, end: &str) -> io::Result<bool> {
+    let text = fs::read_to_string(path)?;
+    if text.contains(entry.trim_end()) {
+        return Ok(false);
+    }
+    let entries = text
+        .match_indices(marker)
+        .filter_map(|(at, _)| {
+            let digits = text[at + marker.len()..]
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .collect::<String>();
+            Some((at, digits.parse::<u8>().ok()?))
+        })
+        .collect::<Vec<_>>();
+    let at = match entries.iter().find(|&&(_, other)| other > day) {
+        Some(&(at, _)) => at,
+        None => {
+            let after = entries.last().map_or(0, |&(at, _)| at + marker.len());
+            match text[after..].find(end) {
+                Some(offset) if !end.is_empty() => after + offset,
+                _ => text.len(),
+            }
+        }
+    };
+    let updated = if at == text.len() {
+        format!("{}\n\n{}\n", text.trim_end(), entry.trim_end())
+    } else {
+        format!("{}{}{}", &text[..at], entry, &text[at..])
+    };
+    fs::write(path, updated)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join("aoc-scaffold-test");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/day01")).unwrap();
+        fs::write(root.join("src/day01/mod.rs"), "").unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "pub mod day01;\npub mod day03;\npub mod days;\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/days.rs"),
+            "pub fn all() -> Vec<Day> {\n    vec![\n        Day {\n            day: 1,\n        },\n    ]\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[[bin]]\nname = \"aoc\"\n\n[[bin]]\nname = \"day03\"\npath = \"src/day03/main.rs\"\n",
+        )
+        .unwrap();
+
+        let changes = new_day(&root, 2).unwrap();
+        assert_eq!(changes.len(), 13);
+        let read = |path| fs::read_to_string(root.join(path)).unwrap();
+        assert!(read("src/day02/mod.rs").contains("pub struct Day02;"));
+        assert!(read("src/day02/main.rs").starts_with("use aoc::day02::{part1, part2};"));
+        assert_eq!(
+            read("src/lib.rs"),
+            "pub mod day01;\npub mod day02;\npub mod day03;\npub mod days;\n"
+        );
+        let days = read("src/days.rs");
+        assert!(days.contains("        },\n        Day {\n            day: 2,\n"));
+        assert!(days.ends_with("explanation: None,\n        },\n    ]\n}\n"));
+        assert!(read("Cargo.toml").starts_with(
+            "[[bin]]\nname = \"aoc\"\n\n[[bin]]\nname = \"day02\"\npath = \"src/day02/main.rs\"\n\n[[bin]]\nname = \"day03\""
+        ));
+
+        // an existing day is refused, and nothing changes
+        let error = new_day(&root, 2).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert!(new_day(&root, 1).is_err());
+        assert_eq!(read("src/lib.rs").matches("day02").count(), 1);
+
+        // a run interrupted before mod.rs was written can be repeated
+        fs::remove_file(root.join("src/day02/mod.rs")).unwrap();
+        assert_eq!(
+            new_day(&root, 2).unwrap(),
+            [format!(
+                "created {}",
+                root.join("src/day02/mod.rs").display()
+            )]
+        );
+
+        // even if it stopped after registering the day but before writing any files
+        fs::remove_dir_all(root.join("src/day02")).unwrap();
+        let changes = new_day(&root, 2).unwrap();
+        assert_eq!(changes.len(), 10);
+        assert!(changes.iter().all(|change| change.starts_with("created ")));
+        assert_eq!(read("src/lib.rs").matches("day02").count(), 1);
+
+        // days after the last one go at the end
+        new_day(&root, 25).unwrap();
+        assert!(read("src/lib.rs").ends_with("pub mod day03;\npub mod day25;\npub mod days;\n"));
+        assert!(read("Cargo.toml").ends_with("path = \"src/day25/main.rs\"\n"));
+        assert!(new_day(&root, 26).is_err());
+    }
+}
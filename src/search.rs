@@ -0,0 +1,284 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/**
+ * Something that can be searched: a set of nodes, each with edges to its neighbors.
+ */
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /**
+     * The nodes reachable in one step from `node`, with the cost of each step. Breadth-first
+     * search and flood fill ignore the costs and count every step as 1.
+     */
+    fn neighbors(&self, node: &Self::Node) -> impl Iterator<Item = (Self::Node, usize)>;
+}
+
+/**
+ * The result of a search: the distance to every node it reached, how it got there, and the goal
+ * it stopped at, if any.
+ */
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    pub distances: HashMap<N, usize>,
+    pub predecessors: HashMap<N, N>,
+    pub goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new(start: N) -> Search<N> {
+        Search {
+            distances: HashMap::from([(start, 0)]),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    /**
+     * The nodes from the start to `node`, inclusive, or None if the search didn't reach it.
+     */
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal.as_ref()?)
+    }
+}
+
+/**
+ * Search outwards from `start` in order of the number of steps taken, stopping at the first node
+ * for which `is_goal` returns true. Pass `|_| false` to reach every node.
+ */
+pub fn bfs<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> Search<G::Node> {
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        let distance = search.distances[&node] + 1;
+        for (next, _) in graph.neighbors(&node) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), distance);
+                search.predecessors.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/**
+ * Search outwards from `start` in order of total cost, stopping at the first node for which
+ * `is_goal` returns true. Pass `|_| false` to find the cheapest route to every node.
+ */
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Search<G::Node> {
+    a_star(graph, start, is_goal, |_| 0)
+}
+
+/**
+ * Like `dijkstra`, but visiting the nodes that look closest to a goal first. `heuristic` estimates
+ * the remaining cost from a node to the nearest goal; it must never overestimate, or the route
+ * found may not be the cheapest. It needn't be consistent: a node that's found again by a cheaper
+ * route after it was visited is visited again.
+ */
+pub fn a_star<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> usize,
+) -> Search<G::Node> {
+    let mut search = Search::new(start.clone());
+    let mut queue = BinaryHeap::from([Entry {
+        estimate: heuristic(&start),
+        distance: 0,
+        node: start,
+    }]);
+
+    while let Some(Entry { node, distance, .. }) = queue.pop() {
+        // a cheaper route to this node has been queued since
+        if distance > search.distances[&node] {
+            continue;
+        }
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        for (next, cost) in graph.neighbors(&node) {
+            let next_distance = distance + cost;
+            if search
+                .distances
+                .get(&next)
+                .is_some_and(|&known| known <= next_distance)
+            {
+                continue;
+            }
+            search.distances.insert(next.clone(), next_distance);
+            search.predecessors.insert(next.clone(), node.clone());
+            queue.push(Entry {
+                estimate: next_distance + heuristic(&next),
+                distance: next_distance,
+                node: next,
+            });
+        }
+    }
+    search
+}
+
+/**
+ * Every node reachable from `start`, including `start` itself.
+ */
+pub fn flood_fill<G: Graph>(graph: &G, start: G::Node) -> HashSet<G::Node> {
+    let mut reached = HashSet::from([start.clone()]);
+    let mut frontier = vec![start];
+
+    while let Some(node) = frontier.pop() {
+        for (next, _) in graph.neighbors(&node) {
+            if reached.insert(next.clone()) {
+                frontier.push(next);
+            }
+        }
+    }
+    reached
+}
+
+/**
+ * A node waiting in the priority queue, ordered so the lowest estimate comes out first.
+ */
+struct Entry<N> {
+    estimate: usize,
+    /// The distance to `node` when it was queued
+    distance: usize,
+    node: N,
+}
+
+impl<N> PartialEq for Entry<N> {
+    fn eq(&self, other: &Entry<N>) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N> Eq for Entry<N> {}
+
+impl<N> PartialOrd for Entry<N> {
+    fn partial_cmp(&self, other: &Entry<N>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Entry<N> {
+    fn cmp(&self, other: &Entry<N>) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * A weighted graph where the direct route from A to D is longer than going around.
+     */
+    struct Roads;
+
+    impl Graph for Roads {
+        type Node = char;
+
+        fn neighbors(&self, node: &char) -> impl Iterator<Item = (char, usize)> {
+            let edges: &[(char, usize)] = match node {
+                'A' => &[('B', 1), ('D', 10)],
+                'B' => &[('C', 2)],
+                'C' => &[('D', 3)],
+                _ => &[],
+            };
+            edges.iter().copied()
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs(&Roads, 'A', |node| *node == 'D');
+        assert_eq!(search.goal_distance(), Some(1));
+        assert_eq!(search.goal_path(), Some(vec!['A', 'D']));
+
+        let search = bfs(&Roads, 'B', |_| false);
+        assert_eq!(search.goal, None);
+        assert_eq!(search.distance(&'D'), Some(2));
+        assert_eq!(search.distance(&'A'), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra(&Roads, 'A', |node| *node == 'D');
+        assert_eq!(search.goal_distance(), Some(6));
+        assert_eq!(search.goal_path(), Some(vec!['A', 'B', 'C', 'D']));
+        assert_eq!(search.predecessors.get(&'C'), Some(&'B'));
+    }
+
+    #[test]
+    fn test_a_star() {
+        let heuristic = |node: &char| (b'D' - *node as u8) as usize;
+        let search = a_star(&Roads, 'A', |node| *node == 'D', heuristic);
+        assert_eq!(search.goal_distance(), Some(6));
+    }
+
+    /**
+     * A graph where the cheapest route to C is found only after C has been visited, if the
+     * heuristic overrates A.
+     */
+    struct Detour;
+
+    impl Graph for Detour {
+        type Node = char;
+
+        fn neighbors(&self, node: &char) -> impl Iterator<Item = (char, usize)> {
+            let edges: &[(char, usize)] = match node {
+                'S' => &[('A', 1), ('C', 3)],
+                'A' => &[('C', 1)],
+                'C' => &[('G', 3)],
+                _ => &[],
+            };
+            edges.iter().copied()
+        }
+    }
+
+    #[test]
+    fn test_a_star_inconsistent() {
+        // admissible, since A really is 4 from G, but not consistent: A to C costs 1 and C says 0
+        let heuristic = |node: &char| if *node == 'A' { 4 } else { 0 };
+        let search = a_star(&Detour, 'S', |node| *node == 'G', heuristic);
+        assert_eq!(search.goal_distance(), Some(5));
+        assert_eq!(search.goal_path(), Some(vec!['S', 'A', 'C', 'G']));
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        assert_eq!(flood_fill(&Roads, 'B'), HashSet::from(['B', 'C', 'D']));
+    }
+}
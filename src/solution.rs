@@ -0,0 +1,132 @@
+use std::any::Any;
+use std::fmt::Display;
+
+use crate::parse::ParseError;
+
+/**
+ * A day's solution, split into a parse step and the two parts that share its output.
+ */
+pub trait Solution {
+    type Parsed;
+    type Part1: Answer;
+    type Part2: Answer;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError>;
+    fn part1(&self, parsed: &Self::Parsed) -> Self::Part1;
+    fn part2(&self, parsed: &Self::Parsed) -> Self::Part2;
+
+    /**
+     * Check one record of the input (see `validate::Records`) on its own. That's parsing it,
+     * unless the day's parser also checks references between records, which a lone record can't
+     * satisfy.
+     */
+    fn check_record(&self, record: &str) -> Result<(), ParseError> {
+        self.parse(record).map(|_| ())
+    }
+
+    /**
+     * Every problem with the whole input: the one `parse` stops at, or more for days that can
+     * carry on past it.
+     */
+    fn problems(&self, input: &str) -> Vec<ParseError> {
+        self.parse(input).err().into_iter().collect()
+    }
+}
+
+/**
+ * Anything a part can return. Every displayable type is an answer; `Unsolved` marks a part that
+ * hasn't been implemented yet.
+ */
+pub trait Answer {
+    const SOLVED: bool = true;
+
+    fn answer(&self) -> Option<String>;
+}
+
+impl<T: Display> Answer for T {
+    fn answer(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Answer for Unsolved {
+    const SOLVED: bool = false;
+
+    fn answer(&self) -> Option<String> {
+        None
+    }
+}
+
+/**
+ * Object-safe view of a `Solution`, so days with different parsed and answer types can be run
+ * from one list. The parsed input is passed around as `dyn Any` and handed back to the same
+ * solution that produced it.
+ */
+pub trait DynSolution {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn part1(&self, parsed: &dyn Any) -> Option<String>;
+    fn part2(&self, parsed: &dyn Any) -> Option<String>;
+    fn has_part1(&self) -> bool;
+    fn has_part2(&self) -> bool;
+    fn check_record(&self, record: &str) -> Result<(), ParseError>;
+    fn problems(&self, input: &str) -> Vec<ParseError>;
+
+    /**
+     * Return the answer for the given part (1 or 2), or None if that part isn't implemented.
+     */
+    fn part(&self, part: u8, parsed: &dyn Any) -> Option<String> {
+        match part {
+            1 => self.part1(parsed),
+            2 => self.part2(parsed),
+            _ => None,
+        }
+    }
+
+    fn has_part(&self, part: u8) -> bool {
+        match part {
+            1 => self.has_part1(),
+            2 => self.has_part2(),
+            _ => false,
+        }
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+    S::Parsed: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+    fn part1(&self, parsed: &dyn Any) -> Option<String> {
+        Solution::part1(self, downcast::<S>(parsed)).answer()
+    }
+    fn part2(&self, parsed: &dyn Any) -> Option<String> {
+        Solution::part2(self, downcast::<S>(parsed)).answer()
+    }
+    fn has_part1(&self) -> bool {
+        S::Part1::SOLVED
+    }
+    fn has_part2(&self) -> bool {
+        S::Part2::SOLVED
+    }
+    fn check_record(&self, record: &str) -> Result<(), ParseError> {
+        Solution::check_record(self, record)
+    }
+    fn problems(&self, input: &str) -> Vec<ParseError> {
+        Solution::problems(self, input)
+    }
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> &S::Parsed
+where
+    S::Parsed: 'static,
+{
+    parsed
+        .downcast_ref::<S::Parsed>()
+        .expect("parsed input came from a different solution")
+}
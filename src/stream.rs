@@ -0,0 +1,109 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+
+use super::parse::ParseError;
+
+/**
+ * Why a streamed input couldn't be solved: reading it failed, or it didn't match the day's format.
+ */
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "could not read input: {}", e),
+            StreamError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> StreamError {
+        StreamError::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> StreamError {
+        StreamError::Parse(e)
+    }
+}
+
+/**
+ * Call `f` with each line of `reader` and its 1-based line number, without the line ending. One
+ * buffer is reused for every line, so memory use is bounded by the longest line.
+ *
+ * Blank lines at the end are skipped, so the lines match those of an input loaded with
+ * `InputSource::load`, which trims trailing newlines.
+ */
+pub fn for_each_line(
+    mut reader: impl BufRead,
+    mut f: impl FnMut(usize, &str) -> Result<(), ParseError>,
+) -> Result<(), StreamError> {
+    let mut line = String::new();
+    let mut number = 0;
+    // blank lines are held back until something follows them
+    let mut blank = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        number += 1;
+        let text = line.trim_end_matches(['\n', '\r']);
+        if text.is_empty() {
+            blank += 1;
+            continue;
+        }
+        for held in number - blank..number {
+            f(held, "")?;
+        }
+        blank = 0;
+        f(number, text)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(input: &str) -> Vec<(usize, String)> {
+        let mut lines = vec![];
+        for_each_line(input.as_bytes(), |number, line| {
+            lines.push((number, line.to_string()));
+            Ok(())
+        })
+        .unwrap();
+        lines
+    }
+
+    #[test]
+    fn test_for_each_line() {
+        let expected = [(1, "a"), (2, ""), (3, "b")]
+            .map(|(number, line)| (number, line.to_string()))
+            .to_vec();
+        assert_eq!(lines("a\n\nb"), expected);
+        assert_eq!(lines("a\r\n\r\nb\n\n\n"), expected);
+        assert_eq!(lines(""), vec![]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = for_each_line("1\nx".as_bytes(), |number, line| match line {
+            "x" => Err(ParseError::line(1, number, line, "a number")),
+            _ => Ok(()),
+        })
+        .unwrap_err();
+        assert!(matches!(
+            error,
+            StreamError::Parse(ParseError { line: 2, .. })
+        ));
+    }
+}
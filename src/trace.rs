@@ -0,0 +1,193 @@
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+/**
+ * One step in how an answer was reached, like a directory that was counted towards it, with named
+ * values describing it.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub label: String,
+    pub fields: Vec<(String, Value)>,
+}
+
+impl Step {
+    pub fn new(label: impl Into<String>) -> Step {
+        Step {
+            label: label.into(),
+            fields: vec![],
+        }
+    }
+
+    /**
+     * Add a named value to the step.
+     */
+    pub fn with(mut self, name: &str, value: impl Into<Value>) -> Step {
+        self.fields.push((name.to_string(), value.into()));
+        self
+    }
+
+    /**
+     * The step as a JSON object, with the label under `step` followed by the fields.
+     */
+    pub fn to_json(&self) -> Value {
+        let mut object = Map::new();
+        object.insert("step".to_string(), Value::String(self.label.clone()));
+        for (name, value) in &self.fields {
+            object.insert(name.clone(), value.clone());
+        }
+        Value::Object(object)
+    }
+}
+
+/**
+ * Shows a step on one line, like `counted directory: path /a/e, size 584`.
+ */
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)?;
+        for (index, (name, value)) in self.fields.iter().enumerate() {
+            let separator = if index == 0 { ": " } else { ", " };
+            match value {
+                Value::String(text) => write!(f, "{}{} {}", separator, name, text)?,
+                value => write!(f, "{}{} {}", separator, name, value)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/**
+ * Somewhere for a solver to send the steps behind its answer.
+ */
+pub trait Tracer {
+    /**
+     * Whether steps are wanted at all. Solvers skip building them when this is false, so solving
+     * without a tracer costs next to nothing.
+     */
+    fn enabled(&self) -> bool {
+        true
+    }
+
+    fn step(&mut self, step: Step);
+}
+
+impl dyn Tracer + '_ {
+    /**
+     * Build and send a step, if steps are wanted.
+     */
+    pub fn emit(&mut self, step: impl FnOnce() -> Step) {
+        if self.enabled() {
+            self.step(step());
+        }
+    }
+}
+
+/**
+ * Ignores every step; used when just solving.
+ */
+pub struct NoTracer;
+
+impl Tracer for NoTracer {
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn step(&mut self, _: Step) {}
+}
+
+/**
+ * Collects every step in memory.
+ */
+impl Tracer for Vec<Step> {
+    fn step(&mut self, step: Step) {
+        self.push(step);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_step() {
+        let step = Step::new("counted directory")
+            .with("path", "/a/e")
+            .with("size", 584);
+        assert_eq!(step.to_string(), "counted directory: path /a/e, size 584");
+        assert_eq!(
+            step.to_json(),
+            json!({"step": "counted directory", "path": "/a/e", "size": 584})
+        );
+        assert_eq!(Step::new("done").to_string(), "done");
+    }
+
+    #[test]
+    fn test_disabled() {
+        let mut built = false;
+        let tracer: &mut dyn Tracer = &mut NoTracer;
+        tracer.emit(|| {
+            built = true;
+            Step::new("unused")
+        });
+        assert!(!built);
+
+        let mut steps = vec![];
+        let tracer: &mut dyn Tracer = &mut steps;
+        tracer.emit(|| Step::new("used"));
+        assert_eq!(steps, vec![Step::new("used")]);
+    }
+
+    fn explain(day: u8, input: &str, part: u8) -> (String, Vec<Step>) {
+        let explanation = crate::days::get(day).unwrap().explanation.unwrap();
+        let mut steps = vec![];
+        let answer = (explanation.run)(input, part, &mut steps).unwrap();
+        (answer, steps)
+    }
+
+    #[test]
+    fn test_explanations() {
+        let (answer, steps) = explain(7, include_str!("day07/inputs/sample.txt"), 1);
+        assert_eq!(answer, "95437");
+        assert_eq!(
+            steps.iter().map(Step::to_string).collect::<Vec<_>>(),
+            vec![
+                "counted directory: path /a, size 94853",
+                "counted directory: path /a/e, size 584",
+            ]
+        );
+        let (answer, steps) = explain(7, include_str!("day07/inputs/sample.txt"), 2);
+        assert_eq!(answer, "24933642");
+        assert_eq!(
+            steps.last().unwrap().to_json(),
+            json!({"step": "deleted directory", "path": "/d", "size": 24933642})
+        );
+
+        let (answer, steps) = explain(13, include_str!("day13/inputs/sample.txt"), 1);
+        assert_eq!(answer, "13");
+        let indices = steps.iter().map(|step| step.fields[0].1.clone());
+        assert_eq!(
+            indices.collect::<Vec<_>>(),
+            vec![json!(1), json!(2), json!(4), json!(6)]
+        );
+
+        // a square for the start and one for each step of the 31-step path
+        let (answer, steps) = explain(12, include_str!("day12/inputs/sample.txt"), 1);
+        assert_eq!(answer, "31");
+        assert_eq!(steps.len(), 32);
+        assert_eq!(steps[0].to_string(), "square: step 0, x 0, y 0, height a");
+        assert_eq!(steps[31].to_string(), "square: step 31, x 5, y 2, height z");
+
+        // the valves opened release exactly the answer's pressure
+        for part in [1, 2] {
+            let (answer, steps) = explain(16, include_str!("day16/inputs/sample.txt"), part);
+            let released = steps
+                .iter()
+                .map(|step| step.to_json()["released"].as_i64().unwrap())
+                .sum::<i64>();
+            assert_eq!(released.to_string(), answer);
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use super::batch;
+use super::days::Day;
+use super::parse::{self, ParseError};
+
+/**
+ * How a day's input splits into records that its parser accepts on their own, so a check can
+ * carry on past the first bad one.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Records {
+    /// Every line is a record, like day 02's rounds
+    Lines,
+    /// Every block of lines between blank lines is a record, like day 11's monkeys
+    Blocks,
+    /// The input only parses as a whole, like a map, so only its first problem can be found
+    /// (unless the day's `Solution::problems` carries on, like day 05's moves)
+    Whole,
+}
+
+/**
+ * Format-level numbers about an input, whichever day it's for.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub lines: usize,
+    /// Runs of lines separated by blank lines
+    pub blocks: usize,
+    /// The shortest and longest line, in characters
+    pub widths: (usize, usize),
+    /// How many integers appear, counting a leading `-`
+    pub integers: usize,
+    /// The smallest and largest of them, if there are any
+    pub range: Option<(i128, i128)>,
+}
+
+impl Stats {
+    pub fn of(input: &str) -> Stats {
+        let lines = input.split('\n').map(|line| line.trim_end_matches('\r'));
+        let widths = lines.clone().map(|line| line.chars().count());
+        let blocks = parse::blocks(0, input).len();
+
+        let mut integers = 0;
+        let mut range: Option<(i128, i128)> = None;
+        for line in lines.clone() {
+            for (offset, _) in line.match_indices(|c: char| c.is_ascii_digit()) {
+                let before = line[..offset].chars().next_back();
+                if before.is_some_and(|c| c.is_ascii_digit()) {
+                    continue;
+                }
+                let digits = line[offset..]
+                    .find(|c: char| !c.is_ascii_digit())
+                    .map_or(&line[offset..], |end| &line[offset..offset + end]);
+                let Ok(mut value) = digits.parse::<i128>() else {
+                    continue;
+                };
+                if before == Some('-') {
+                    value = -value;
+                }
+                integers += 1;
+                range = Some(range.map_or((value, value), |(min, max)| {
+                    (min.min(value), max.max(value))
+                }));
+            }
+        }
+
+        Stats {
+            lines: lines.count(),
+            blocks,
+            widths: (widths.clone().min().unwrap_or(0), widths.max().unwrap_or(0)),
+            integers,
+            range,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Lines: {} in {} block(s), {} to {} characters long",
+            self.lines, self.blocks, self.widths.0, self.widths.1
+        )?;
+        match self.range {
+            Some((min, max)) => write!(f, "Integers: {}, from {} to {}", self.integers, min, max),
+            None => write!(f, "Integers: none"),
+        }
+    }
+}
+
+/**
+ * The result of checking an input against a day's format.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub records: Records,
+    /// How many records were parsed
+    pub checked: usize,
+    pub problems: Vec<ParseError>,
+    pub stats: Stats,
+}
+
+/**
+ * Check `input` against `day`'s format without solving it. Each record is checked on its own and
+ * every one that fails is reported, with its line numbers counted from the start of the input.
+ * When every record passes, the input is also checked as a whole, which catches problems that span
+ * records (like ragged map rows, a reference to a missing name or a move that takes more crates
+ * than its stack holds).
+ *
+ * A parser that panics is reported as a problem with the record's first line.
+ */
+pub fn check(day: &Day, input: &str) -> Report {
+    let solution = day.solution(&day.params(&[]));
+    let check = |first: usize, text: &str, whole: bool| {
+        let result = batch::quietly(|| {
+            panic::catch_unwind(AssertUnwindSafe(|| {
+                if whole {
+                    solution.problems(text)
+                } else {
+                    solution.check_record(text).err().into_iter().collect()
+                }
+            }))
+        });
+        match result {
+            Ok(problems) => problems
+                .into_iter()
+                .map(|mut e| {
+                    e.line += first - 1;
+                    e
+                })
+                .collect(),
+            Err(_) => vec![ParseError::line(
+                day.day,
+                first,
+                text.split('\n').next().unwrap_or(""),
+                "input the parser can handle without panicking",
+            )],
+        }
+    };
+
+    let records = match day.records {
+        Records::Lines => parse::lines(input)
+            .map(|(number, line)| (number, line.to_string()))
+            .collect(),
+        Records::Blocks => parse::blocks(day.day, input)
+            .iter()
+            .map(|block| {
+                let lines = block.lines();
+                let text = lines.iter().map(|&(_, line)| line).collect::<Vec<_>>();
+                (lines[0].0, text.join("\n"))
+            })
+            .collect(),
+        Records::Whole => vec![],
+    };
+    let mut problems = records
+        .iter()
+        .flat_map(|(first, text)| check(*first, text, false))
+        .collect::<Vec<_>>();
+    if problems.is_empty() {
+        problems.extend(check(1, input, true));
+    }
+
+    Report {
+        records: day.records,
+        checked: records.len().max(1),
+        problems,
+        stats: Stats::of(input),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use crate::random::Rng;
+
+    #[test]
+    fn test_valid_inputs() {
+        for day in days::all() {
+            let mut inputs = vec![day.input.to_string()];
+            inputs.extend((1..=3).map(|seed| (day.generate)(&mut Rng::new(seed), 6)));
+            for input in inputs {
+                let report = check(&day, &input);
+                assert_eq!(report.problems, vec![], "day {}", day.day);
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_problem() {
+        let day = days::get(2).unwrap();
+        let report = check(&day, "A Y\nB Q\nC Z\nD X");
+        assert_eq!(report.checked, 4);
+        assert_eq!(
+            report.problems,
+            vec![
+                ParseError::new(2, 2, 3, "Q", "X, Y or Z"),
+                ParseError::new(2, 4, 1, "D", "A, B or C"),
+            ]
+        );
+
+        let day = days::get(11).unwrap();
+        let sample = include_str!("day11/inputs/sample.txt");
+        let report = check(
+            &day,
+            &sample.replacen("divisible by 19", "divisible by x", 1),
+        );
+        assert_eq!(report.checked, 4);
+        assert_eq!(report.problems.len(), 1);
+        assert_eq!(report.problems[0].line, 11);
+    }
+
+    #[test]
+    fn test_stack_names() {
+        let day = days::get(5).unwrap();
+        let input = "[A]         [B]\n 1   2   3   10\n\nmove 1 from 1 to 2";
+        let report = check(&day, input);
+        assert_eq!(
+            report.problems,
+            vec![ParseError::new(
+                5,
+                2,
+                14,
+                "10",
+                "a single-character stack name"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_references() {
+        let day = days::get(21).unwrap();
+        let sample = include_str!("day21/inputs/sample.txt");
+        let report = check(&day, &format!("{}\nefgh: zzzz * abcd", sample));
+        assert_eq!(report.checked, 16);
+        assert_eq!(
+            report.problems,
+            vec![ParseError::new(21, 16, 7, "zzzz", "the name of a monkey")]
+        );
+
+        let day = days::get(11).unwrap();
+        let sample = include_str!("day11/inputs/sample.txt");
+        let report = check(&day, &sample.replacen("monkey 2", "monkey 7", 1));
+        assert_eq!(report.problems.len(), 1);
+        assert_eq!(
+            (report.problems[0].line, report.problems[0].column),
+            (5, 30)
+        );
+
+        // every move that takes too many crates, not just the first: skipping line 6 leaves stack 1
+        // too short for line 7
+        let day = days::get(5).unwrap();
+        let sample = include_str!("day05/inputs/sample.txt");
+        let report = check(
+            &day,
+            &sample
+                .replace("move 1 from 2 to 1", "move 5 from 2 to 1")
+                .replace("move 1 from 1 to 2", "move 9 from 1 to 2"),
+        );
+        assert_eq!(
+            report.problems.iter().map(|e| e.line).collect::<Vec<_>>(),
+            [6, 7, 9]
+        );
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::of("move 12 from -3 to 4\n\nx\r\n99x100");
+        assert_eq!(
+            stats,
+            Stats {
+                lines: 4,
+                blocks: 2,
+                widths: (0, 20),
+                integers: 5,
+                range: Some((-3, 100)),
+            }
+        );
+    }
+}
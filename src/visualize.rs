@@ -0,0 +1,300 @@
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/**
+ * One picture of a simulation's state, with a short label saying where it is, like `Rock 12`.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub label: String,
+    pub picture: String,
+}
+
+impl Frame {
+    pub fn new(label: impl Into<String>, picture: impl Into<String>) -> Frame {
+        Frame {
+            label: label.into(),
+            picture: picture.into(),
+        }
+    }
+}
+
+/**
+ * Somewhere for a simulation to send frames as it runs.
+ */
+pub trait Visualizer {
+    /**
+     * Whether frames are wanted at all. Simulations skip rendering when this is false, so solving
+     * without a visualizer costs next to nothing.
+     */
+    fn enabled(&self) -> bool {
+        true
+    }
+
+    fn frame(&mut self, frame: Frame);
+}
+
+impl dyn Visualizer + '_ {
+    /**
+     * Render and send a frame, if frames are wanted.
+     */
+    pub fn emit(&mut self, frame: impl FnOnce() -> Frame) {
+        if self.enabled() {
+            self.frame(frame());
+        }
+    }
+}
+
+/**
+ * Ignores every frame; used when just solving.
+ */
+pub struct NoVisualizer;
+
+impl Visualizer for NoVisualizer {
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn frame(&mut self, _: Frame) {}
+}
+
+/**
+ * Collects every frame in memory.
+ */
+impl Visualizer for Vec<Frame> {
+    fn frame(&mut self, frame: Frame) {
+        self.push(frame);
+    }
+}
+
+/**
+ * Writes each frame to its own numbered file (`frame-000001.txt`, ...) in a directory, with the
+ * label on the first line. Stops at the first write error, which `finish` returns.
+ */
+pub struct FrameDump {
+    dir: PathBuf,
+    count: usize,
+    error: Option<io::Error>,
+}
+
+impl FrameDump {
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<FrameDump> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(FrameDump {
+            dir,
+            count: 0,
+            error: None,
+        })
+    }
+
+    /**
+     * The number of frames written, or the error that stopped the dump.
+     */
+    pub fn finish(self) -> io::Result<usize> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.count),
+        }
+    }
+}
+
+impl Visualizer for FrameDump {
+    fn enabled(&self) -> bool {
+        self.error.is_none()
+    }
+
+    fn frame(&mut self, frame: Frame) {
+        self.count += 1;
+        let path = self.dir.join(format!("frame-{:06}.txt", self.count));
+        if let Err(e) = fs::write(&path, format!("{}\n{}\n", frame.label, frame.picture)) {
+            self.error = Some(io::Error::new(
+                e.kind(),
+                format!("{}: {}", path.display(), e),
+            ));
+        }
+    }
+}
+
+/**
+ * A command typed into the player, one per line.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Step,
+    Toggle,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Command {
+    fn parse(line: &str) -> Option<Command> {
+        match line.trim() {
+            "" | "s" => Some(Command::Step),
+            "p" => Some(Command::Toggle),
+            "+" => Some(Command::Faster),
+            "-" => Some(Command::Slower),
+            "q" => Some(Command::Quit),
+            _ => None,
+        }
+    }
+}
+
+/**
+ * Shows frames in the terminal as the simulation produces them.
+ *
+ * Commands are read from stdin a line at a time: Enter (or `s`) steps one frame and pauses, `p`
+ * plays or pauses, `+` and `-` double or halve the speed, and `q` stops showing frames and lets the
+ * simulation finish.
+ */
+pub struct Player {
+    commands: Receiver<Command>,
+    fps: f64,
+    playing: bool,
+    quit: bool,
+    count: usize,
+}
+
+impl Player {
+    pub fn new(fps: f64) -> Player {
+        let (sender, commands) = mpsc::channel();
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                if let Some(command) = Command::parse(&line) {
+                    if sender.send(command).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+        Player {
+            commands,
+            fps,
+            playing: true,
+            quit: false,
+            count: 0,
+        }
+    }
+
+    fn draw(&self, frame: &Frame) {
+        let state = if self.playing { "playing" } else { "paused" };
+        let mut stdout = io::stdout().lock();
+        // clear the screen and move to the top left
+        let _ = write!(
+            stdout,
+            "\x1b[2J\x1b[H{}\n\n{} | frame {} | {} at {} fps\n\
+             [Enter] step  p play/pause  +/- speed  q quit\n",
+            frame.picture, frame.label, self.count, state, self.fps
+        );
+        let _ = stdout.flush();
+    }
+
+    /**
+     * Apply a command. Returns true if it moves on to the next frame.
+     */
+    fn command(&mut self, command: Command) -> bool {
+        match command {
+            Command::Step => {
+                self.playing = false;
+                true
+            }
+            Command::Toggle => {
+                self.playing = !self.playing;
+                self.playing
+            }
+            Command::Faster => {
+                self.fps = (self.fps * 2.0).min(1000.0);
+                false
+            }
+            Command::Slower => {
+                self.fps = (self.fps / 2.0).max(0.25);
+                false
+            }
+            Command::Quit => {
+                self.quit = true;
+                true
+            }
+        }
+    }
+}
+
+impl Visualizer for Player {
+    fn enabled(&self) -> bool {
+        !self.quit
+    }
+
+    fn frame(&mut self, frame: Frame) {
+        self.count += 1;
+        loop {
+            self.draw(&frame);
+            let command = if self.playing {
+                match self
+                    .commands
+                    .recv_timeout(Duration::from_secs_f64(1.0 / self.fps))
+                {
+                    Ok(command) => command,
+                    Err(RecvTimeoutError::Timeout) => return,
+                    // stdin closed, so nothing can pause us again
+                    Err(RecvTimeoutError::Disconnected) => {
+                        thread::sleep(Duration::from_secs_f64(1.0 / self.fps));
+                        return;
+                    }
+                }
+            } else {
+                match self.commands.recv() {
+                    Ok(command) => command,
+                    Err(_) => {
+                        self.playing = true;
+                        return;
+                    }
+                }
+            };
+            if self.command(command) {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_emit() {
+        let mut frames = vec![];
+        let visualizer: &mut dyn Visualizer = &mut frames;
+        visualizer.emit(|| Frame::new("one", "#"));
+        assert_eq!(frames, [Frame::new("one", "#")]);
+
+        let visualizer: &mut dyn Visualizer = &mut NoVisualizer;
+        visualizer.emit(|| panic!("frames shouldn't be rendered when they aren't wanted"));
+    }
+
+    #[test]
+    fn test_frame_dump() {
+        let dir = std::env::temp_dir().join("aoc-frame-dump-test");
+        let _ = fs::remove_dir_all(&dir);
+        let mut dump = FrameDump::new(&dir).unwrap();
+        dump.frame(Frame::new("Step 1", "#.\n.#"));
+        dump.frame(Frame::new("Step 2", ".#\n#."));
+        assert_eq!(dump.finish().unwrap(), 2);
+        assert_eq!(
+            fs::read_to_string(dir.join("frame-000002.txt")).unwrap(),
+            "Step 2\n.#\n#.\n"
+        );
+    }
+
+    #[test]
+    fn test_commands() {
+        assert_eq!(Command::parse(""), Some(Command::Step));
+        assert_eq!(Command::parse(" p "), Some(Command::Toggle));
+        assert_eq!(Command::parse("x"), None);
+    }
+}
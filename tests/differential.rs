@@ -0,0 +1,164 @@
+/*!
+ * Checks the optimized solvers against their naive reference versions on thousands of generated
+ * inputs. A failing case is shrunk to the smallest input that still disagrees before it's
+ * reported.
+ */
+
+use std::fmt::Debug;
+
+use aoc::grid::Grid;
+use aoc::property::{agree, shrink_number, shrink_vec, Checker, Failure};
+use aoc::{day06, day08, day20};
+
+fn assert_holds<T: Debug>(result: Result<(), Failure<T>>) {
+    if let Err(failure) = result {
+        panic!(
+            "seed {} failed: {}\nshrunk {} times to {:?}",
+            failure.seed, failure.message, failure.shrinks, failure.input
+        );
+    }
+}
+
+/**
+ * Each item replaced in turn by each of its smaller versions.
+ */
+fn shrink_items<T: Clone>(items: &[T], shrink: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    items
+        .iter()
+        .enumerate()
+        .flat_map(|(index, item)| {
+            shrink(item).into_iter().map(move |smaller| {
+                let mut items = items.to_vec();
+                items[index] = smaller;
+                items
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn test_day06_find_marker() {
+    let checker = Checker {
+        cases: 2000,
+        max_size: 60,
+        ..Checker::default()
+    };
+    assert_holds(checker.check(
+        |rng, size| day06::shared::parse_signal(&day06::generate::input(rng, size)).unwrap(),
+        |signal| {
+            let mut candidates = shrink_vec(signal, 0);
+            candidates.extend(shrink_items(signal, |&c| match c {
+                'a' => vec![],
+                _ => vec!['a'],
+            }));
+            candidates
+        },
+        |signal| {
+            for size in [4, 14] {
+                agree(
+                    day06::shared::find_marker_naive(signal, size),
+                    day06::shared::find_marker(signal, size),
+                )?;
+            }
+            Ok(())
+        },
+    ));
+}
+
+#[test]
+fn test_day08_visible_trees() {
+    let to_grid = |rows: &Vec<Vec<usize>>| {
+        let mut grid = Grid::new(rows[0].len(), 0, 0);
+        for row in rows {
+            grid.push_row(row.clone());
+        }
+        grid
+    };
+    let checker = Checker {
+        cases: 2000,
+        max_size: 12,
+        ..Checker::default()
+    };
+    assert_holds(checker.check(
+        |rng, size| {
+            let grid = day08::shared::parse_grid(&day08::generate::input(rng, size)).unwrap();
+            grid.rows().map(<[usize]>::to_vec).collect::<Vec<_>>()
+        },
+        |rows| {
+            // drop rows or columns, keeping at least one of each, or lower single trees
+            let mut candidates = shrink_vec(rows, 1);
+            if rows[0].len() > 1 {
+                candidates.extend((0..rows[0].len()).map(|x| {
+                    rows.iter()
+                        .map(|row| [&row[..x], &row[x + 1..]].concat())
+                        .collect()
+                }));
+            }
+            candidates.extend(shrink_items(rows, |row| {
+                shrink_items(row, |&height| {
+                    shrink_number(height as i64)
+                        .into_iter()
+                        .map(|smaller| smaller as usize)
+                        .collect()
+                })
+            }));
+            candidates
+        },
+        |rows| {
+            let grid = to_grid(rows);
+            agree(day08::part1::solve_naive(&grid), day08::part1::solve(&grid))
+        },
+    ));
+}
+
+/**
+ * Day 20's generator makes files with exactly one 0, so shrinking keeps to that too.
+ */
+fn shrink_encrypted(encrypted: &[i64]) -> Vec<Vec<i64>> {
+    let mut candidates = shrink_vec(encrypted, 3);
+    candidates.extend(shrink_items(encrypted, |&n| {
+        shrink_number(n)
+            .into_iter()
+            .filter(|&smaller| smaller != 0)
+            .collect()
+    }));
+    candidates.retain(|candidate| candidate.iter().filter(|&&n| n == 0).count() == 1);
+    candidates
+}
+
+#[test]
+fn test_day20_mix() {
+    let generate =
+        |rng: &mut _, size| day20::shared::parse_input(&day20::generate::input(rng, size)).unwrap();
+    let checker = Checker {
+        cases: 2000,
+        max_size: 40,
+        ..Checker::default()
+    };
+    assert_holds(checker.check(
+        generate,
+        |encrypted| shrink_encrypted(encrypted),
+        |encrypted| {
+            agree(
+                day20::part1::solve_naive(encrypted),
+                day20::part1::solve(encrypted),
+            )
+        },
+    ));
+
+    let checker = Checker {
+        cases: 1000,
+        max_size: 25,
+        ..Checker::default()
+    };
+    assert_holds(checker.check(
+        generate,
+        |encrypted| shrink_encrypted(encrypted),
+        |encrypted| {
+            agree(
+                day20::part2::solve_naive(encrypted),
+                day20::part2::solve(encrypted),
+            )
+        },
+    ));
+}
@@ -0,0 +1,89 @@
+/*!
+ * Checks every day's input generator: the same seed always gives the same input, and the inputs
+ * it gives can be parsed and solved.
+ */
+
+use std::thread;
+
+use aoc::days;
+use aoc::random::Rng;
+
+/**
+ * Parts that can't run on every generated input.
+ */
+const SKIP: [(u8, u8, &str); 2] = [
+    (
+        17,
+        2,
+        "assumes the tower repeats every so many jet patterns, which short patterns don't",
+    ),
+    (19, 2, "too slow in debug builds"),
+];
+
+#[test]
+fn test_same_seed_same_input() {
+    for day in days::all() {
+        let generate = |seed| (day.generate)(&mut Rng::new(seed), 6);
+        assert_eq!(generate(7), generate(7), "day {}", day.day);
+        assert_ne!(generate(7), generate(8), "day {}", day.day);
+    }
+}
+
+#[test]
+fn test_generated_inputs_solve() {
+    let days = days::all();
+    let failures = thread::scope(|scope| {
+        let handles = days
+            .iter()
+            .flat_map(|day| (1..=3).map(move |seed| (day, seed)))
+            .map(|(day, seed)| {
+                let handle = scope.spawn(move || {
+                    let input = (day.generate)(&mut Rng::new(seed), 6);
+                    let solution = day.solution(&day.params(&[]));
+                    let parsed = match solution.parse(&input) {
+                        Ok(parsed) => parsed,
+                        Err(e) => return vec![format!("could not parse input: {}", e)],
+                    };
+                    for part in [1, 2] {
+                        if !SKIP.iter().any(|&(d, p, _)| (d, p) == (day.day, part)) {
+                            solution.part(part, parsed.as_ref());
+                        }
+                    }
+                    vec![]
+                });
+                (day.day, seed, handle)
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .flat_map(|(day, seed, handle)| {
+                let failures = handle
+                    .join()
+                    .unwrap_or_else(|_| vec!["panicked".to_string()]);
+                failures
+                    .into_iter()
+                    .map(move |failure| format!("day {:02} seed {}: {}", day, seed, failure))
+            })
+            .collect::<Vec<_>>()
+    });
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+/**
+ * Day 21's generator builds `root`'s two sides to be equal, so `humn` already yells the answer to
+ * part 2.
+ */
+#[test]
+fn test_day21_human_answer() {
+    let day = days::get(21).unwrap();
+    for seed in 1..=5 {
+        let input = (day.generate)(&mut Rng::new(seed), 20);
+        let human = input
+            .lines()
+            .find_map(|line| line.strip_prefix("humn: "))
+            .unwrap();
+        let solution = day.solution(&day.params(&[]));
+        let parsed = solution.parse(&input).unwrap();
+        assert_eq!(solution.part(2, parsed.as_ref()).unwrap(), human);
+    }
+}
@@ -0,0 +1,74 @@
+use aoc::day15::part1::{parse_sensors, Point};
+use aoc::day19::shared::parse_blueprints;
+use aoc::days;
+use aoc::parse::ParseError;
+use aoc::solution::Solution;
+
+#[test]
+fn test_parsers_are_public() {
+    let sensors = parse_sensors(include_str!("../src/day15/inputs/sample.txt")).unwrap();
+    assert_eq!(sensors.len(), 14);
+    assert_eq!(sensors[0], (Point { x: 2, y: 18 }, Point { x: -2, y: 15 }));
+
+    let blueprints = parse_blueprints(include_str!("../src/day19/inputs/sample.txt")).unwrap();
+    assert_eq!(blueprints.len(), 2);
+    assert_eq!(blueprints[1].geode_ingredients.obsidian, 12);
+}
+
+#[test]
+fn test_solutions_are_public() {
+    let day = aoc::day15::Day15 {
+        row: 10,
+        max_x: 20,
+        max_y: 20,
+    };
+    let sensors = day
+        .parse(include_str!("../src/day15/inputs/sample.txt"))
+        .unwrap();
+    assert_eq!(day.part1(&sensors), 26);
+    assert_eq!(day.part2(&sensors), 56000011);
+
+    let day01 = days::get(1).unwrap();
+    let solution = day01.solution(&day01.params(&[]));
+    let parsed = solution
+        .parse(include_str!("../src/day01/inputs/sample.txt"))
+        .unwrap();
+    assert_eq!(solution.part(1, parsed.as_ref()), Some("24000".to_string()));
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = aoc::day02::shared::parse_rounds("A Y\nB Q").unwrap_err();
+    assert_eq!(error, ParseError::new(2, 2, 3, "Q", "X, Y or Z"));
+
+    let error = aoc::day10::shared::parse_cycles("noop\naddx 3\njump 4").unwrap_err();
+    assert_eq!(error, ParseError::new(10, 3, 1, "jump", "addx or noop"));
+
+    let error = aoc::day21::shared::parse_input("root: pppw + sjmn\ndbpl 5").unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.text.as_str()),
+        (2, 1, "dbpl 5")
+    );
+
+    let day15 = days::get(15).unwrap();
+    let solution = day15.solution(&day15.params(&[]));
+    let error = solution
+        .parse("Sensor at x=2, y=18: closest beacon is at x=-2, y=99999999999")
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.to_string(),
+        "day 15, line 1, column 51: expected a coordinate, found \"99999999999\""
+    );
+}
+
+#[test]
+fn test_params_are_not_truncated() {
+    // a row past i32::MAX would wrap around to row 10 if it were narrowed
+    let day15 = days::get(15).unwrap();
+    let solution = day15.solution(&day15.params(&[("row".to_string(), (1 << 32) + 10)]));
+    let parsed = solution
+        .parse(include_str!("../src/day15/inputs/sample.txt"))
+        .unwrap();
+    assert_eq!(solution.part(1, parsed.as_ref()), Some("0".to_string()));
+}
@@ -0,0 +1,89 @@
+/*!
+ * Runs every day against each of its `inputs/sample*.txt` files and checks the answers in the
+ * matching `sample*.answers.txt` file. A new sample case is just those two files.
+ */
+
+use std::path::Path;
+use std::thread;
+
+use aoc::batch::Outcome;
+use aoc::corpus::{self, Entry, Status};
+use aoc::days::{self, Day};
+
+/**
+ * Every `sample*.txt` in the day's inputs directory, paired with its answers file. They're laid
+ * out like a corpus, so `corpus::entries` finds them alongside `input.txt`.
+ */
+fn samples(day: u8) -> Vec<Entry> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(format!("day{:02}", day))
+        .join("inputs");
+    corpus::entries(&dir)
+        .unwrap_or_else(|e| panic!("Could not read {}: {}", dir.display(), e))
+        .into_iter()
+        .filter(|entry| entry.name.starts_with("sample"))
+        .collect()
+}
+
+/**
+ * Run one sample through the corpus check, returning a description of each way it went wrong.
+ * Parts the answers file leaves out aren't checked.
+ */
+fn check(day: &Day, sample: &Entry) -> Vec<String> {
+    corpus::check(day, sample, &[1, 2])
+        .into_iter()
+        .filter_map(|check| {
+            let part = match check.row.part {
+                Some(part) => format!("part {}: ", part),
+                None => String::new(),
+            };
+            match (check.status, check.row.outcome) {
+                (Status::Pass | Status::Missing, _) => None,
+                (Status::Fail { expected }, Outcome::Answer(answer)) => {
+                    Some(format!("{}expected {:?}, got {:?}", part, expected, answer))
+                }
+                (_, Outcome::NotImplemented) => Some(format!("{}isn't implemented", part)),
+                (_, Outcome::Error(e)) => Some(format!("{}{}", part, e)),
+                (_, Outcome::Answer(answer)) => Some(format!("{}{:?}", part, answer)),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_samples() {
+    let days = days::all();
+    let samples = days
+        .iter()
+        .flat_map(|day| {
+            samples(day.day)
+                .into_iter()
+                .map(move |sample| (day, sample))
+        })
+        .collect::<Vec<_>>();
+    assert!(!samples.is_empty(), "no samples found");
+
+    // some samples take a while in debug builds, so run them all at once
+    let failures = thread::scope(|scope| {
+        let handles = samples
+            .iter()
+            .map(|(day, sample)| scope.spawn(move || check(day, sample)))
+            .collect::<Vec<_>>();
+        samples
+            .iter()
+            .zip(handles)
+            .flat_map(|((day, sample), handle)| {
+                let failures = handle
+                    .join()
+                    .unwrap_or_else(|_| vec!["panicked".to_string()]);
+                failures
+                    .into_iter()
+                    .map(|failure| format!("day {:02} {}: {}", day.day, sample.name, failure))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    });
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
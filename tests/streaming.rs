@@ -0,0 +1,88 @@
+/*!
+ * Checks that the streaming `run_reader` entry points give the same answers as `run` on the
+ * samples, the real inputs and generated inputs.
+ */
+
+use std::fmt::Debug;
+use std::io::{BufReader, Cursor};
+
+use aoc::days;
+use aoc::parse::ParseError;
+use aoc::random::Rng;
+use aoc::stream::StreamError;
+use aoc::{day01, day02, day06, day10};
+
+/**
+ * The inputs to compare on: the bundled sample and real input, and a few generated ones.
+ */
+fn inputs(day: u8) -> Vec<String> {
+    let entry = days::get(day).unwrap();
+    let sample = std::fs::read_to_string(format!(
+        "{}/src/day{:02}/inputs/sample.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        day
+    ))
+    .unwrap();
+    let mut inputs = vec![
+        sample.trim_end_matches(['\n', '\r']).to_string(),
+        entry.input.to_string(),
+    ];
+    inputs.extend((1..=5).map(|seed| (entry.generate)(&mut Rng::new(seed), 50)));
+    inputs
+}
+
+fn assert_same<T: PartialEq + Debug>(
+    day: u8,
+    run: fn(&str) -> Result<T, ParseError>,
+    run_reader: fn(BufReader<Cursor<String>>) -> Result<T, StreamError>,
+) {
+    for input in inputs(day) {
+        let expected = run(&input).unwrap();
+        // a tiny buffer, so lines and windows straddle refills
+        let reader = BufReader::with_capacity(3, Cursor::new(input.clone()));
+        assert_eq!(run_reader(reader).unwrap(), expected, "day {}", day);
+        // streamed input usually ends with a newline, unlike the bundled inputs
+        let input = input + "\n";
+        let reader = BufReader::new(Cursor::new(input));
+        assert_eq!(run_reader(reader).unwrap(), expected, "day {}", day);
+    }
+}
+
+#[test]
+fn test_same_answers() {
+    assert_same(1, day01::part1::run, day01::part1::run_reader);
+    assert_same(1, day01::part2::run, day01::part2::run_reader);
+    assert_same(2, day02::part1::run, day02::part1::run_reader);
+    assert_same(2, day02::part2::run, day02::part2::run_reader);
+    assert_same(6, day06::part1::run, day06::part1::run_reader);
+    assert_same(6, day06::part2::run, day06::part2::run_reader);
+    assert_same(10, day10::part1::run, day10::part1::run_reader);
+    assert_same(10, day10::part2::run, day10::part2::run_reader);
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = day02::part1::run_reader("A Y\nB Q\n".as_bytes()).unwrap_err();
+    match error {
+        StreamError::Parse(e) => assert_eq!(e, day02::part1::run("A Y\nB Q").unwrap_err()),
+        StreamError::Io(e) => panic!("unexpected I/O error: {}", e),
+    }
+
+    let error = day06::part1::run_reader("abcA".as_bytes()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "day 6, line 1, column 4: expected a lowercase letter, found \"A\""
+    );
+
+    // the marker comes before the bad letter, but the whole datastream is still checked
+    let error = day06::part1::run_reader("abcdefA\n".as_bytes()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        day06::part1::run("abcdefA").unwrap_err().to_string()
+    );
+    let error = day06::part1::run_reader("abcd\nefgh".as_bytes()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "day 6, line 1, column 5: expected the end of the datastream, found \"e\""
+    );
+}